@@ -0,0 +1,172 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+/// A validation scope that records up to `N` failed checks instead of returning on the first,
+/// without allocating.
+///
+/// This works like [`Checks`](crate::bool::Checks), but stores the errors inline. Once `N` errors
+/// have been recorded, further errors are dropped and only counted, see
+/// [`ArrayErrors::overflowed`].
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::ArrayChecks;
+///
+/// let mut checks = ArrayChecks::<&str, 2>::new();
+/// checks
+///     .then_err(true, "first")
+///     .then_err(false, "skipped")
+///     .then_err(true, "second")
+///     .then_err(true, "third");
+///
+/// let errors = checks.finish().expect_err("should fail");
+/// assert_eq!(errors.iter().copied().collect::<Vec<_>>(), ["first", "second"]);
+/// assert_eq!(errors.overflowed(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayChecks<E, const N: usize> {
+    errors: ArrayErrors<E, N>,
+}
+
+impl<E, const N: usize> ArrayChecks<E, N> {
+    /// Creates a new, empty [`ArrayChecks`] scope.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { errors: ArrayErrors { errors: [const { None }; N], len: 0, overflowed: 0 } }
+    }
+
+    /// Records `err` if `condition` is `true`.
+    pub fn then_err(&mut self, condition: bool, err: E) -> &mut Self {
+        if condition {
+            self.errors.push(err);
+        }
+        self
+    }
+
+    /// Runs `factory` and records the error it creates if `condition` is `true`.
+    ///
+    /// `factory` is run even if the scope is full, so that the error can be counted.
+    pub fn then_err_with(&mut self, condition: bool, factory: impl FnOnce() -> E) -> &mut Self {
+        if condition {
+            self.errors.push(factory());
+        }
+        self
+    }
+
+    /// Records the error of `result`, if it is [`Err`].
+    pub fn check(&mut self, result: Result<(), E>) -> &mut Self {
+        if let Err(err) = result {
+            self.errors.push(err);
+        }
+        self
+    }
+
+    /// The errors recorded so far.
+    #[must_use]
+    pub const fn errors(&self) -> &ArrayErrors<E, N> {
+        &self.errors
+    }
+
+    /// Ends the scope, returning all recorded errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrayErrors`] if any check failed.
+    pub fn finish(self) -> Result<(), ArrayErrors<E, N>> {
+        if self.errors.total() == 0 { Ok(()) } else { Err(self.errors) }
+    }
+}
+
+impl<E, const N: usize> Default for ArrayChecks<E, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The errors recorded by an [`ArrayChecks`] scope.
+///
+/// Displays as a numbered list of the errors, followed by the number of dropped errors, if any.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayErrors<E, const N: usize> {
+    errors: [Option<E>; N],
+    len: usize,
+    overflowed: usize,
+}
+
+impl<E, const N: usize> ArrayErrors<E, N> {
+    /// The number of recorded errors, at most `N`.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no recorded errors.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of errors that were dropped because the scope was full.
+    #[must_use]
+    pub const fn overflowed(&self) -> usize {
+        self.overflowed
+    }
+
+    /// The total number of failed checks, including dropped errors.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.len + self.overflowed
+    }
+
+    /// Returns an iterator over the recorded errors.
+    pub fn iter(&self) -> core::iter::Flatten<core::slice::Iter<'_, Option<E>>> {
+        self.errors.iter().flatten()
+    }
+
+    fn push(&mut self, err: E) {
+        match self.errors.get_mut(self.len) {
+            Some(slot) => {
+                *slot = Some(err);
+                self.len += 1;
+            }
+            None => self.overflowed += 1,
+        }
+    }
+}
+
+impl<E, const N: usize> IntoIterator for ArrayErrors<E, N> {
+    type Item = E;
+    type IntoIter = core::iter::Flatten<core::array::IntoIter<Option<E>, N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter().flatten()
+    }
+}
+
+impl<'a, E, const N: usize> IntoIterator for &'a ArrayErrors<E, N> {
+    type Item = &'a E;
+    type IntoIter = core::iter::Flatten<core::slice::Iter<'a, Option<E>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<E: Display, const N: usize> Display for ArrayErrors<E, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.total() {
+            1 => write!(f, "1 error:")?,
+            total => write!(f, "{total} errors:")?,
+        }
+        for (index, error) in self.iter().enumerate() {
+            write!(f, "\n    {index}: {error}")?;
+        }
+        if self.overflowed > 0 {
+            write!(f, "\n    ... and {} more", self.overflowed)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Error, const N: usize> Error for ArrayErrors<E, N> {}
@@ -0,0 +1,90 @@
+use alloc::vec::Vec;
+
+use crate::error::MultiError;
+
+/// A validation scope that records every failed check instead of returning on the first.
+///
+/// Where [`Then::then_err`](crate::bool::Then::then_err) short-circuits through `?`, [`Checks`]
+/// keeps every error, so that all the problems with a form or config can be reported at once.
+/// Once all checks have run, [`Checks::finish`] returns every error as a [`MultiError`].
+///
+/// For a fixed capacity variant that does not allocate, see
+/// [`ArrayChecks`](crate::bool::ArrayChecks).
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::{Checks, Then};
+///
+/// fn validate(name: &str, age: u32) -> Result<(), fluent_result::error::MultiError<&'static str>> {
+///     let mut checks = Checks::new();
+///     checks
+///         .then_err(name.is_empty(), "name is empty")
+///         .then_err(age < 18, "must be 18 or older")
+///         .check((age > 150).then_err("age is implausible"));
+///     checks.finish()
+/// }
+///
+/// assert!(validate("alice", 21).is_ok());
+///
+/// let errors = validate("", 16).expect_err("should fail");
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors.to_string(), "2 errors:\n    0: name is empty\n    1: must be 18 or older");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Checks<E> {
+    errors: Vec<E>,
+}
+
+impl<E> Checks<E> {
+    /// Creates a new, empty [`Checks`] scope.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    /// Records `err` if `condition` is `true`.
+    pub fn then_err(&mut self, condition: bool, err: E) -> &mut Self {
+        if condition {
+            self.errors.push(err);
+        }
+        self
+    }
+
+    /// Runs `factory` and records the error it creates if `condition` is `true`.
+    pub fn then_err_with(&mut self, condition: bool, factory: impl FnOnce() -> E) -> &mut Self {
+        if condition {
+            self.errors.push(factory());
+        }
+        self
+    }
+
+    /// Records the error of `result`, if it is [`Err`].
+    pub fn check(&mut self, result: Result<(), E>) -> &mut Self {
+        if let Err(err) = result {
+            self.errors.push(err);
+        }
+        self
+    }
+
+    /// The errors recorded so far.
+    #[must_use]
+    pub fn errors(&self) -> &[E] {
+        &self.errors
+    }
+
+    /// Ends the scope, returning all recorded errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MultiError`] of all recorded errors if any check failed.
+    pub fn finish(self) -> Result<(), MultiError<E>> {
+        if self.errors.is_empty() { Ok(()) } else { Err(MultiError::new(self.errors)) }
+    }
+}
+
+impl<E> Default for Checks<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,244 @@
+/// Returns early with an error if a condition is `false`.
+///
+/// This is the inverse of [`Then::then_err`](crate::bool::Then::then_err), which returns an error
+/// if the condition is `true`. `ensure!(cond, err)` expands to `(!cond).then_err_with(|| err)?`,
+/// so the error is lazily evaluated and converted with [`From`], like any other use of `?`.
+///
+/// With the `alloc` feature, a format string followed by arguments creates a
+/// [`String`](alloc::string::String) error. Any other literal is used as-is, such as a
+/// `&'static str` or an integer status code. A string literal with braces but no arguments is a
+/// compile error: the macro cannot tell string literals apart from other literals, so it cannot
+/// format inline captures such as `"{name} is invalid"`. Pass the arguments explicitly instead.
+///
+/// # Errors
+///
+/// Returns `err` from the enclosing function if `cond` is `false`.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::ensure;
+///
+/// fn validate_age(age: u32) -> Result<u32, &'static str> {
+///     ensure!(age >= 18, "must be 18 or older");
+///     Ok(age)
+/// }
+///
+/// assert_eq!(validate_age(21), Ok(21));
+/// assert_eq!(validate_age(16), Err("must be 18 or older"));
+///
+/// fn validate_name(name: &str) -> Result<&str, String> {
+///     ensure!(name.len() <= 8, "name `{}` is longer than {} characters", name, 8);
+///     Ok(name)
+/// }
+///
+/// assert_eq!(validate_name("alice"), Ok("alice"));
+/// assert_eq!(validate_name("bartholomew"), Err("name `bartholomew` is longer than 8 characters".to_string()));
+///
+/// fn validate_status(status: u16) -> Result<u16, u16> {
+///     ensure!(status < 400, 500_u16);
+///     Ok(status)
+/// }
+///
+/// assert_eq!(validate_status(404), Err(500));
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($cond:expr, $fmt:literal, $($arg:tt)+) => {
+        $crate::bool::Then::then_err_with(!($cond), || $crate::__format_err!($fmt, $($arg)+))?
+    };
+    ($cond:expr, $fmt:literal $(,)?) => {
+        $crate::bool::Then::then_err_with(!($cond), || $crate::__literal_err!($fmt))?
+    };
+    ($cond:expr, $err:expr $(,)?) => {
+        $crate::bool::Then::then_err_with(!($cond), || $err)?
+    };
+}
+
+/// Returns early with an error if a condition is `true`.
+///
+/// `ensure_not!(cond, err)` expands to `cond.then_err_with(|| err)?`, see [`ensure!`] for details
+/// on the error.
+///
+/// # Errors
+///
+/// Returns `err` from the enclosing function if `cond` is `true`.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::ensure_not;
+///
+/// fn withdraw(balance: u32, amount: u32) -> Result<u32, &'static str> {
+///     ensure_not!(amount > balance, "insufficient funds");
+///     Ok(balance - amount)
+/// }
+///
+/// assert_eq!(withdraw(10, 3), Ok(7));
+/// assert_eq!(withdraw(3, 10), Err("insufficient funds"));
+/// ```
+#[macro_export]
+macro_rules! ensure_not {
+    ($cond:expr, $fmt:literal, $($arg:tt)+) => {
+        $crate::bool::Then::then_err_with($cond, || $crate::__format_err!($fmt, $($arg)+))?
+    };
+    ($cond:expr, $fmt:literal $(,)?) => {
+        $crate::bool::Then::then_err_with($cond, || $crate::__literal_err!($fmt))?
+    };
+    ($cond:expr, $err:expr $(,)?) => {
+        $crate::bool::Then::then_err_with($cond, || $err)?
+    };
+}
+
+/// Returns early with an error.
+///
+/// `bail!(err)` expands to `true.then_err(err)?`, see [`ensure!`] for details on the error.
+///
+/// # Errors
+///
+/// Always returns `err` from the enclosing function.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::bail;
+///
+/// fn parse_flag(value: &str) -> Result<bool, &'static str> {
+///     match value {
+///         "yes" => Ok(true),
+///         "no" => Ok(false),
+///         _ => bail!("expected `yes` or `no`"),
+///     }
+/// }
+///
+/// assert_eq!(parse_flag("yes"), Ok(true));
+/// assert_eq!(parse_flag("maybe"), Err("expected `yes` or `no`"));
+/// ```
+#[macro_export]
+macro_rules! bail {
+    ($fmt:literal, $($arg:tt)+) => {
+        $crate::bail!($crate::__format_err!($fmt, $($arg)+))
+    };
+    ($fmt:literal $(,)?) => {
+        $crate::bail!($crate::__literal_err!($fmt))
+    };
+    ($err:expr $(,)?) => {{
+        $crate::bool::Then::then_err(true, $err)?;
+        ::core::unreachable!()
+    }};
+}
+
+/// Returns early with an error if two values are not equal.
+///
+/// `ensure_eq!(a, b, err)` expands to `(a != b).then_err_with(|| err)?`, see [`ensure!`] for
+/// details on the error. Both values are taken by reference.
+///
+/// # Errors
+///
+/// Returns `err` from the enclosing function if `a` is not equal to `b`.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::ensure_eq;
+///
+/// fn check_version(version: u32) -> Result<(), String> {
+///     ensure_eq!(version, 2, "unsupported version {}", version);
+///     Ok(())
+/// }
+///
+/// assert_eq!(check_version(2), Ok(()));
+/// assert_eq!(check_version(1), Err("unsupported version 1".to_string()));
+/// ```
+#[macro_export]
+macro_rules! ensure_eq {
+    ($left:expr, $right:expr, $fmt:literal, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                $crate::bool::Then::then_err_with(*left != *right, || $crate::__format_err!($fmt, $($arg)+))?
+            }
+        }
+    };
+    ($left:expr, $right:expr, $fmt:literal $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::bool::Then::then_err_with(*left != *right, || $crate::__literal_err!($fmt))?,
+        }
+    };
+    ($left:expr, $right:expr, $err:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::bool::Then::then_err_with(*left != *right, || $err)?,
+        }
+    };
+}
+
+/// Unwraps an [`Option`], returning early with an error if it is [`None`].
+///
+/// `ensure_some!(opt, err)` expands to `opt.ok_or_else(|| err)?`, see [`ensure!`] for details on
+/// the error.
+///
+/// # Errors
+///
+/// Returns `err` from the enclosing function if `opt` is [`None`].
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::ensure_some;
+///
+/// fn first_word(text: &str) -> Result<&str, &'static str> {
+///     let word = ensure_some!(text.split_whitespace().next(), "text is blank");
+///     Ok(word)
+/// }
+///
+/// assert_eq!(first_word("hello world"), Ok("hello"));
+/// assert_eq!(first_word("   "), Err("text is blank"));
+/// ```
+#[macro_export]
+macro_rules! ensure_some {
+    ($opt:expr, $fmt:literal, $($arg:tt)+) => {
+        ::core::option::Option::ok_or_else($opt, || $crate::__format_err!($fmt, $($arg)+))?
+    };
+    ($opt:expr, $fmt:literal $(,)?) => {
+        ::core::option::Option::ok_or_else($opt, || $crate::__literal_err!($fmt))?
+    };
+    ($opt:expr, $err:expr $(,)?) => {
+        ::core::option::Option::ok_or_else($opt, || $err)?
+    };
+}
+
+/// Formats an error message, for the format forms of the `ensure!` family of macros.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_err {
+    ($($arg:tt)+) => {
+        $crate::__private::format!($($arg)+)
+    };
+}
+
+/// Formats an error message, for the format forms of the `ensure!` family of macros.
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_err {
+    ($($arg:tt)+) => {
+        ::core::compile_error!("the format form of this macro requires the `alloc` feature of `fluent_result`")
+    };
+}
+
+/// Uses a literal as-is as the error, for the `ensure!` family of macros. A string literal with
+/// braces is rejected, since `macro_rules` cannot tell it apart from other literals in order to
+/// format it, and `format!` rejects literals that are not strings.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __literal_err {
+    ($lit:literal) => {{
+        const {
+            ::core::assert!(
+                !$crate::__private::is_format_string(::core::stringify!($lit)),
+                "a format string in this macro needs explicit arguments, such as `\"{{}}\", value`"
+            );
+        }
+        $lit
+    }};
+}
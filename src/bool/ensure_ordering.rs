@@ -0,0 +1,102 @@
+use core::cmp::Ordering;
+
+/// An extension trait for [`Ordering`] that provides guards, converting the result of a
+/// comparison into a [`Result`].
+///
+/// This allows a comparison, such as one from [`Ord::cmp`], to be used as a guard with the `?`
+/// operator.
+#[sealed::sealed]
+pub trait EnsureOrdering {
+    /// Returns [`Ok(())`](Ok) if the ordering is [`Ordering::Less`], and [`Err(err)`](Err)
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err(err)`](Err) if the ordering is not [`Ordering::Less`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::EnsureOrdering;
+    ///
+    /// fn check_index(index: usize, len: usize) -> Result<usize, &'static str> {
+    ///     index.cmp(&len).ensure_less("index out of bounds")?;
+    ///     Ok(index)
+    /// }
+    ///
+    /// assert_eq!(check_index(2, 3), Ok(2));
+    /// assert_eq!(check_index(3, 3), Err("index out of bounds"));
+    /// ```
+    fn ensure_less<E>(self, err: E) -> Result<(), E>;
+
+    /// Returns [`Ok(())`](Ok) if the ordering is [`Ordering::Less`] or [`Ordering::Equal`], and
+    /// [`Err(err)`](Err) otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err(err)`](Err) if the ordering is [`Ordering::Greater`].
+    fn ensure_less_or_equal<E>(self, err: E) -> Result<(), E>;
+
+    /// Returns [`Ok(())`](Ok) if the ordering is [`Ordering::Equal`], and [`Err(err)`](Err)
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err(err)`](Err) if the ordering is not [`Ordering::Equal`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::EnsureOrdering;
+    ///
+    /// let version = (1, 2);
+    /// assert_eq!(version.cmp(&(1, 2)).ensure_equal("version mismatch"), Ok(()));
+    /// assert_eq!(version.cmp(&(1, 3)).ensure_equal("version mismatch"), Err("version mismatch"));
+    /// ```
+    fn ensure_equal<E>(self, err: E) -> Result<(), E>;
+
+    /// Returns [`Ok(())`](Ok) if the ordering is [`Ordering::Greater`] or [`Ordering::Equal`],
+    /// and [`Err(err)`](Err) otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err(err)`](Err) if the ordering is [`Ordering::Less`].
+    fn ensure_greater_or_equal<E>(self, err: E) -> Result<(), E>;
+
+    /// Returns [`Ok(())`](Ok) if the ordering is [`Ordering::Greater`], and [`Err(err)`](Err)
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err(err)`](Err) if the ordering is not [`Ordering::Greater`].
+    fn ensure_greater<E>(self, err: E) -> Result<(), E>;
+}
+
+/// The implementation of [`EnsureOrdering`] for [`Ordering`].
+#[sealed::sealed]
+impl EnsureOrdering for Ordering {
+    #[inline]
+    fn ensure_less<E>(self, err: E) -> Result<(), E> {
+        if self.is_lt() { Ok(()) } else { Err(err) }
+    }
+
+    #[inline]
+    fn ensure_less_or_equal<E>(self, err: E) -> Result<(), E> {
+        if self.is_le() { Ok(()) } else { Err(err) }
+    }
+
+    #[inline]
+    fn ensure_equal<E>(self, err: E) -> Result<(), E> {
+        if self.is_eq() { Ok(()) } else { Err(err) }
+    }
+
+    #[inline]
+    fn ensure_greater_or_equal<E>(self, err: E) -> Result<(), E> {
+        if self.is_ge() { Ok(()) } else { Err(err) }
+    }
+
+    #[inline]
+    fn ensure_greater<E>(self, err: E) -> Result<(), E> {
+        if self.is_gt() { Ok(()) } else { Err(err) }
+    }
+}
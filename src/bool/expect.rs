@@ -1,123 +1,198 @@
+use crate::expect::mode::Mode;
+use crate::expect::{FailureKind, fail};
+
+/// An extension trait for `bool` that allows asserting `bool` values in a given [`Mode`].
+///
+/// For the common modes, see [`dbg::Expect`] and [`rls::Expect`].
+#[sealed::sealed]
+pub trait ExpectIn {
+    /// Asserts that the value is `true`, if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and the value is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::ExpectIn;
+    /// use fluent_result::expect::mode::Release;
+    ///
+    /// true.assert_true_in::<Release>();
+    /// ```
+    fn assert_true_in<M: Mode>(self);
+
+    /// Asserts that the value is `true` with `msg` as the assertion message, if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and the value is `false`.
+    fn expect_true_in<M: Mode>(self, msg: &str);
+
+    /// Asserts that the value is `false`, if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and the value is `true`.
+    fn assert_false_in<M: Mode>(self);
+
+    /// Asserts that the value is `false` with `msg` as the assertion message, if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and the value is `true`.
+    fn expect_false_in<M: Mode>(self, msg: &str);
+}
+
+#[sealed::sealed]
+impl ExpectIn for bool {
+    #[inline]
+    #[track_caller]
+    fn assert_true_in<M: Mode>(self) {
+        if M::ENABLED && !self {
+            fail(FailureKind::ExpectedTrue, format_args!("assertion failed: expected `true` but was `false`"));
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn expect_true_in<M: Mode>(self, msg: &str) {
+        if M::ENABLED && !self {
+            fail(FailureKind::ExpectedTrue, format_args!("{msg}"));
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn assert_false_in<M: Mode>(self) {
+        if M::ENABLED && self {
+            fail(FailureKind::ExpectedFalse, format_args!("assertion failed: expected `false` but was `true`"));
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn expect_false_in<M: Mode>(self, msg: &str) {
+        if M::ENABLED && self {
+            fail(FailureKind::ExpectedFalse, format_args!("{msg}"));
+        }
+    }
+}
+
 /// [`debug_assert!`]s for `bool` values.
 pub mod dbg {
+    use super::ExpectIn;
+    use crate::expect::mode::Debug;
+
     /// An extension trait for `bool` that allows [`debug_assert!`]ing `bool` values.
+    ///
+    /// This is equivalent to [`ExpectIn`] in [`Debug`](struct@Debug) mode.
     #[sealed::sealed]
-    pub trait Expect {
+    pub trait Expect: ExpectIn + Sized {
         /// [`debug_assert!`]s that the value is `true`.
         ///
         /// # Panics
         ///
         /// Panics in debug builds if the value is `false`.
-        fn assert_true(self);
+        #[inline]
+        #[track_caller]
+        fn assert_true(self) {
+            self.assert_true_in::<Debug>();
+        }
 
         /// [`debug_assert!`]s that the value is `true` with `msg` as the assertion message.
         ///
         /// # Panics
         ///
         /// Panics in debug builds if the value is `false`.
-        fn expect_true(self, msg: &str);
+        #[inline]
+        #[track_caller]
+        fn expect_true(self, msg: &str) {
+            self.expect_true_in::<Debug>(msg);
+        }
 
         /// [`debug_assert!`]s that the value is `false`.
         ///
         /// # Panics
         ///
         /// Panics in debug builds if the value is `true`.
-        fn assert_false(self);
+        #[inline]
+        #[track_caller]
+        fn assert_false(self) {
+            self.assert_false_in::<Debug>();
+        }
 
         /// [`debug_assert!`]s that the value is `false` with `msg` as the assertion message.
         ///
         /// # Panics
         ///
         /// Panics in debug builds if the value is `true`.
-        fn expect_false(self, msg: &str);
-    }
-
-    #[sealed::sealed]
-    impl Expect for bool {
-        #[inline]
-        #[track_caller]
-        fn assert_true(self) {
-            debug_assert!(self, "assertion failed: expected `true` but was `false`");
-        }
-
-        #[inline]
-        #[track_caller]
-        fn expect_true(self, msg: &str) {
-            debug_assert!(self, "{}", msg);
-        }
-
-        #[inline]
-        #[track_caller]
-        fn assert_false(self) {
-            debug_assert!(!self, "assertion failed: expected `false` but was `true`");
-        }
-
         #[inline]
         #[track_caller]
         fn expect_false(self, msg: &str) {
-            debug_assert!(!self, "{}", msg);
+            self.expect_false_in::<Debug>(msg);
         }
     }
+
+    #[sealed::sealed]
+    impl Expect for bool {}
 }
 
 /// [`assert!`]s for `bool` values.
 pub mod rls {
+    use super::ExpectIn;
+    use crate::expect::mode::Release;
+
     /// An extension trait for `bool` that allows [`assert!`]ing `bool` values.
+    ///
+    /// This is equivalent to [`ExpectIn`] in [`Release`] mode.
     #[sealed::sealed]
-    pub trait Expect {
+    pub trait Expect: ExpectIn + Sized {
         /// [`assert!`]s that the value is `true`.
         ///
         /// # Panics
         ///
         /// Panics if the value is `false`.
-        fn assert_true(self);
+        #[inline]
+        #[track_caller]
+        fn assert_true(self) {
+            self.assert_true_in::<Release>();
+        }
 
         /// [`assert!`]s that the value is `true` with `msg` as the assertion message.
         ///
         /// # Panics
         ///
         /// Panics if the value is `false`.
-        fn expect_true(self, msg: &str);
+        #[inline]
+        #[track_caller]
+        fn expect_true(self, msg: &str) {
+            self.expect_true_in::<Release>(msg);
+        }
 
         /// [`assert!`]s that the value is `false`.
         ///
         /// # Panics
         ///
         /// Panics if the value is `true`.
-        fn assert_false(self);
+        #[inline]
+        #[track_caller]
+        fn assert_false(self) {
+            self.assert_false_in::<Release>();
+        }
 
         /// [`assert!`]s that the value is `false` with `msg` as the assertion message.
         ///
         /// # Panics
         ///
         /// Panics if the value is `true`.
-        fn expect_false(self, msg: &str);
-    }
-
-    #[sealed::sealed]
-    impl Expect for bool {
-        #[inline]
-        #[track_caller]
-        fn assert_true(self) {
-            assert!(self, "assertion failed: expected `true` but was `false`");
-        }
-
-        #[inline]
-        #[track_caller]
-        fn expect_true(self, msg: &str) {
-            assert!(self, "{}", msg);
-        }
-
-        #[inline]
-        #[track_caller]
-        fn assert_false(self) {
-            assert!(!self, "assertion failed: expected `false` but was `true`");
-        }
-
         #[inline]
         #[track_caller]
         fn expect_false(self, msg: &str) {
-            assert!(!self, "{}", msg);
+            self.expect_false_in::<Release>(msg);
         }
     }
+
+    #[sealed::sealed]
+    impl Expect for bool {}
 }
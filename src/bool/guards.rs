@@ -0,0 +1,149 @@
+/// Returns [`Ok(())`](Ok) if every condition is `true`, and the error paired with the first
+/// `false` condition otherwise.
+///
+/// Conditions after the first `false` one are not inspected. Note the errors are eagerly
+/// evaluated, for a lazily evaluated version see [`all_or_err_with`].
+///
+/// # Errors
+///
+/// Returns the error paired with the first `false` condition.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::all_or_err;
+///
+/// fn validate(name: &str, age: u32) -> Result<(), &'static str> {
+///     all_or_err([(!name.is_empty(), "name is empty"), (age >= 18, "must be 18 or older")])
+/// }
+///
+/// assert_eq!(validate("alice", 21), Ok(()));
+/// assert_eq!(validate("", 16), Err("name is empty"));
+/// assert_eq!(validate("alice", 16), Err("must be 18 or older"));
+/// ```
+#[inline]
+pub fn all_or_err<E>(conditions: impl IntoIterator<Item = (bool, E)>) -> Result<(), E> {
+    conditions.into_iter().find(|(condition, _)| !condition).map_or(Ok(()), |(_, err)| Err(err))
+}
+
+/// Returns [`Ok(())`](Ok) if every condition is `true`, and runs the factory paired with the
+/// first `false` condition to create an error otherwise.
+///
+/// Conditions after the first `false` one are not inspected. Note the errors are lazily
+/// evaluated, for an eagerly evaluated version see [`all_or_err`].
+///
+/// Every factory must have the same type. To pair each condition with a different closure, take
+/// them as `&dyn Fn() -> E`.
+///
+/// # Errors
+///
+/// Returns the error created by the factory paired with the first `false` condition.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::all_or_err_with;
+///
+/// fn validate(fields: &[(&str, &str)]) -> Result<(), String> {
+///     all_or_err_with(fields.iter().map(|(name, value)| (!value.is_empty(), move || format!("{name} is empty"))))
+/// }
+///
+/// assert_eq!(validate(&[("name", "alice"), ("city", "paris")]), Ok(()));
+/// assert_eq!(validate(&[("name", "alice"), ("city", "")]), Err("city is empty".to_string()));
+///
+/// fn validate_age(name: &str, age: u32) -> Result<(), String> {
+///     let conditions: [(bool, &dyn Fn() -> String); 2] = [
+///         (!name.is_empty(), &|| "name is empty".to_string()),
+///         (age >= 18, &|| format!("{name} must be 18 or older")),
+///     ];
+///     all_or_err_with(conditions)
+/// }
+///
+/// assert_eq!(validate_age("alice", 16), Err("alice must be 18 or older".to_string()));
+/// ```
+#[inline]
+pub fn all_or_err_with<E>(conditions: impl IntoIterator<Item = (bool, impl FnOnce() -> E)>) -> Result<(), E> {
+    conditions.into_iter().find(|(condition, _)| !condition).map_or(Ok(()), |(_, factory)| Err(factory()))
+}
+
+/// Returns [`Ok(())`](Ok) if any condition is `true`, and [`Err(err)`](Err) otherwise.
+///
+/// Conditions after the first `true` one are not inspected.
+///
+/// # Errors
+///
+/// Returns [`Err(err)`](Err) if no condition is `true`, including if there are none.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::any_or_err;
+///
+/// let (email, phone) = (None::<&str>, Some("555-0100"));
+/// assert_eq!(any_or_err([email.is_some(), phone.is_some()], "no contact"), Ok(()));
+/// assert_eq!(any_or_err([email.is_some()], "no contact"), Err("no contact"));
+/// ```
+#[inline]
+pub fn any_or_err<E>(conditions: impl IntoIterator<Item = bool>, err: E) -> Result<(), E> {
+    if conditions.into_iter().any(core::convert::identity) { Ok(()) } else { Err(err) }
+}
+
+/// An extension trait for iterators of `bool` conditions, that provides guards which report the
+/// index of the failing condition.
+#[sealed::sealed]
+pub trait GuardIter: Iterator<Item = bool> + Sized {
+    /// Returns [`Ok(())`](Ok) if every condition is `true`, and runs `factory` with the index of
+    /// the first `false` condition to create an error otherwise.
+    ///
+    /// Conditions after the first `false` one are not inspected.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error created by `factory` for the first `false` condition.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::GuardIter;
+    ///
+    /// let lines = ["a", "b", "", "d"];
+    /// let result = lines.iter().map(|line| !line.is_empty()).all_or_err_at(|index| format!("line {index} is empty"));
+    /// assert_eq!(result, Err("line 2 is empty".to_string()));
+    ///
+    /// let result = lines[..2].iter().map(|line| !line.is_empty()).all_or_err_at(|index| index);
+    /// assert_eq!(result, Ok(()));
+    /// ```
+    fn all_or_err_at<E>(self, factory: impl FnOnce(usize) -> E) -> Result<(), E>;
+
+    /// Returns the index of the first `true` condition, and [`Err(err)`](Err) if there is none.
+    ///
+    /// Conditions after the first `true` one are not inspected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err(err)`](Err) if no condition is `true`, including if there are none.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::GuardIter;
+    ///
+    /// let servers = ["down", "up", "up"];
+    /// assert_eq!(servers.iter().map(|status| *status == "up").position_or_err("all servers down"), Ok(1));
+    /// assert_eq!(servers[..1].iter().map(|status| *status == "up").position_or_err("all servers down"), Err("all servers down"));
+    /// ```
+    fn position_or_err<E>(self, err: E) -> Result<usize, E>;
+}
+
+#[sealed::sealed]
+impl<I: Iterator<Item = bool>> GuardIter for I {
+    #[inline]
+    fn all_or_err_at<E>(mut self, factory: impl FnOnce(usize) -> E) -> Result<(), E> {
+        self.position(|condition| !condition).map_or(Ok(()), |index| Err(factory(index)))
+    }
+
+    #[inline]
+    fn position_or_err<E>(mut self, err: E) -> Result<usize, E> {
+        self.position(core::convert::identity).ok_or(err)
+    }
+}
@@ -1,5 +1,21 @@
+mod array_checks;
+#[cfg(feature = "alloc")]
+mod checks;
+mod ensure;
+mod ensure_ordering;
 mod expect;
+mod guards;
 mod then;
+mod then_option;
+mod then_result;
 
-pub use expect::{dbg, rls};
+pub use crate::{bail, ensure, ensure_eq, ensure_not, ensure_some};
+pub use array_checks::{ArrayChecks, ArrayErrors};
+#[cfg(feature = "alloc")]
+pub use checks::Checks;
+pub use ensure_ordering::EnsureOrdering;
+pub use expect::{ExpectIn, dbg, rls};
+pub use guards::{GuardIter, all_or_err, all_or_err_with, any_or_err};
 pub use then::Then;
+pub use then_option::ThenOption;
+pub use then_result::ThenResult;
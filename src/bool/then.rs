@@ -1,3 +1,7 @@
+use core::ops::ControlFlow;
+
+use crate::error::MessageError;
+
 /// An extension trait for `bool` that provides conversion methods to [`Result`] and [`Option`].
 ///
 /// This trait allows transforming boolean values into [`Result`] or [`Option`] types,
@@ -66,6 +70,27 @@ pub trait Then {
     where
         F: FnOnce() -> E;
 
+    /// Returns [`Err`] with a [`MessageError`] if the `bool` value is `true`, and [`Ok(())`](Ok)
+    /// otherwise.
+    ///
+    /// Unlike `then_err("message")`, the error implements [`Error`](core::error::Error), so it can
+    /// be boxed or nested.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MessageError(msg)`](MessageError) if the `bool` value is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::Then;
+    /// use fluent_result::error::MessageError;
+    ///
+    /// assert_eq!(true.then_msg_err("error"), Err(MessageError("error")));
+    /// assert_eq!(false.then_msg_err("error"), Ok(()));
+    /// ```
+    fn then_msg_err(self, msg: &'static str) -> Result<(), MessageError>;
+
     /// Converts the `bool` value into a [`Result`] by returning [`Ok(on_true)`](Ok) if the `bool`
     /// value is `true`, and [`Err(on_false)`](Err) otherwise.
     ///
@@ -133,6 +158,32 @@ pub trait Then {
     /// ```
     fn to_result_with<T, E>(self, on_true: impl FnOnce() -> T, on_false: impl FnOnce() -> E) -> Result<T, E>;
 
+    /// Returns [`Break(value)`](ControlFlow::Break) if the `bool` value is `true`, and
+    /// [`Continue(())`](ControlFlow::Continue) otherwise.
+    ///
+    /// This is useful for stopping early in [`Iterator::try_for_each`] and similar methods.
+    ///
+    /// Note this value is eagerly evaluated, for a lazily evaluated version see
+    /// [`Then::then_break_with`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::ops::ControlFlow;
+    /// use fluent_result::bool::Then;
+    ///
+    /// let flow = [1, 2, 3].iter().try_for_each(|n| (*n > 1).then_break(*n));
+    /// assert_eq!(flow, ControlFlow::Break(2));
+    /// ```
+    fn then_break<B>(self, value: B) -> ControlFlow<B>;
+
+    /// Runs `factory` to create a [`Break`](ControlFlow::Break) value and returns it if the
+    /// `bool` value is `true`, or returns [`Continue(())`](ControlFlow::Continue) otherwise.
+    ///
+    /// Note this value is lazily evaluated, for an eagerly evaluated version see
+    /// [`Then::then_break`].
+    fn then_break_with<B>(self, factory: impl FnOnce() -> B) -> ControlFlow<B>;
+
     /// Returns [`None`] if the `bool` value is `true`, and [`Some(())`](Some) otherwise.
     ///
     /// This is equivalent to `if self { None } else { Some(()) }`, but may be more convienent for
@@ -174,6 +225,11 @@ impl Then for bool {
         if self { Err(factory()) } else { Ok(()) }
     }
 
+    #[inline]
+    fn then_msg_err(self, msg: &'static str) -> Result<(), MessageError> {
+        self.then_err(MessageError(msg))
+    }
+
     #[inline]
     fn to_result<T, E>(self, on_true: T, on_false: E) -> Result<T, E> {
         if self { Ok(on_true) } else { Err(on_false) }
@@ -184,6 +240,16 @@ impl Then for bool {
         if self { Ok(on_true()) } else { Err(on_false()) }
     }
 
+    #[inline]
+    fn then_break<B>(self, value: B) -> ControlFlow<B> {
+        if self { ControlFlow::Break(value) } else { ControlFlow::Continue(()) }
+    }
+
+    #[inline]
+    fn then_break_with<B>(self, factory: impl FnOnce() -> B) -> ControlFlow<B> {
+        if self { ControlFlow::Break(factory()) } else { ControlFlow::Continue(()) }
+    }
+
     #[inline]
     fn then_none(self) -> Option<()> {
         if self { None } else { Some(()) }
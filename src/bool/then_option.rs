@@ -0,0 +1,134 @@
+/// An extension trait for tri-state flags, `Option<bool>`, that provides the conversions of
+/// [`Then`](crate::bool::Then).
+///
+/// Each method takes an explicit `if_none` value, which is used in place of the flag when it is
+/// [`None`].
+#[sealed::sealed]
+pub trait ThenOption {
+    /// Returns [`Err`] if the flag is `true`, and [`Ok(())`](Ok) if it is `false`. If the flag is
+    /// [`None`], `if_none` is used instead.
+    ///
+    /// Note `err` is eagerly evaluated, for a lazily evaluated version see
+    /// [`ThenOption::then_err_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err(err)`](Err) if the flag, or `if_none`, is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::ThenOption;
+    ///
+    /// let read_only: Option<bool> = None;
+    /// assert_eq!(read_only.then_err(false, "read only"), Ok(()));
+    /// assert_eq!(read_only.then_err(true, "read only"), Err("read only"));
+    ///
+    /// let read_only: Option<bool> = Some(true);
+    /// assert_eq!(read_only.then_err(false, "read only"), Err("read only"));
+    /// ```
+    fn then_err<E>(self, if_none: bool, err: E) -> Result<(), E>;
+
+    /// Runs `factory` to create an [`Err`] value and returns it if the flag is `true`, and returns
+    /// [`Ok(())`](Ok) if it is `false`. If the flag is [`None`], `if_none` is used instead.
+    ///
+    /// Note `factory` is lazily evaluated, for an eagerly evaluated version see
+    /// [`ThenOption::then_err`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the value of `factory` as [`Err`] if the flag, or `if_none`, is `true`.
+    fn then_err_with<E>(self, if_none: bool, factory: impl FnOnce() -> E) -> Result<(), E>;
+
+    /// Converts the flag into a [`Result`] by returning [`Ok(on_true)`](Ok) if it is `true`, and
+    /// [`Err(on_false)`](Err) if it is `false`. If the flag is [`None`], `if_none` is used instead.
+    ///
+    /// Note these values are eagerly evaluated, for a lazily evaluated version see
+    /// [`ThenOption::to_result_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err(on_false)`](Err) if the flag, or `if_none`, is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::ThenOption;
+    ///
+    /// let verified: Option<bool> = None;
+    /// assert_eq!(verified.to_result(false, "access granted", "unverified"), Err("unverified"));
+    ///
+    /// let verified: Option<bool> = Some(true);
+    /// assert_eq!(verified.to_result(false, "access granted", "unverified"), Ok("access granted"));
+    /// ```
+    fn to_result<T, E>(self, if_none: bool, on_true: T, on_false: E) -> Result<T, E>;
+
+    /// Converts the flag into a [`Result`] by running `on_true` to create an [`Ok`] value if it is
+    /// `true`, and running `on_false` to create an [`Err`] value if it is `false`. If the flag is
+    /// [`None`], `if_none` is used instead.
+    ///
+    /// Note these values are lazily evaluated, for an eagerly evaluated version see
+    /// [`ThenOption::to_result`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the value of `on_false` as [`Err`] if the flag, or `if_none`, is `false`.
+    fn to_result_with<T, E>(
+        self,
+        if_none: bool,
+        on_true: impl FnOnce() -> T,
+        on_false: impl FnOnce() -> E,
+    ) -> Result<T, E>;
+
+    /// Returns [`None`] if the flag is `true`, and [`Some(())`](Some) if it is `false`. If the
+    /// flag is [`None`], `if_none` is used instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::ThenOption;
+    ///
+    /// fn visible_name(name: &str, hidden: Option<bool>) -> Option<&str> {
+    ///     hidden.then_none(false)?;
+    ///     Some(name)
+    /// }
+    ///
+    /// assert_eq!(visible_name("alice", None), Some("alice"));
+    /// assert_eq!(visible_name("alice", Some(true)), None);
+    /// ```
+    fn then_none(self, if_none: bool) -> Option<()>;
+}
+
+/// The implementation of [`ThenOption`] for `Option<bool>`.
+#[sealed::sealed]
+impl ThenOption for Option<bool> {
+    #[inline]
+    fn then_err<E>(self, if_none: bool, err: E) -> Result<(), E> {
+        if self.unwrap_or(if_none) { Err(err) } else { Ok(()) }
+    }
+
+    #[inline]
+    fn then_err_with<E>(self, if_none: bool, factory: impl FnOnce() -> E) -> Result<(), E> {
+        if self.unwrap_or(if_none) { Err(factory()) } else { Ok(()) }
+    }
+
+    #[inline]
+    fn to_result<T, E>(self, if_none: bool, on_true: T, on_false: E) -> Result<T, E> {
+        if self.unwrap_or(if_none) { Ok(on_true) } else { Err(on_false) }
+    }
+
+    #[inline]
+    fn to_result_with<T, E>(
+        self,
+        if_none: bool,
+        on_true: impl FnOnce() -> T,
+        on_false: impl FnOnce() -> E,
+    ) -> Result<T, E> {
+        if self.unwrap_or(if_none) { Ok(on_true()) } else { Err(on_false()) }
+    }
+
+    #[inline]
+    fn then_none(self, if_none: bool) -> Option<()> {
+        if self.unwrap_or(if_none) { None } else { Some(()) }
+    }
+}
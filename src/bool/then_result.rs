@@ -0,0 +1,165 @@
+use crate::nested::NestedError;
+
+/// An extension trait for fallible predicates, `Result<bool, E>`, that provides the conversions
+/// of [`Then`](crate::bool::Then).
+///
+/// An error from the predicate itself is preserved as [`NestedError::Outer`], while the error
+/// produced by the check is returned as [`NestedError::Inner`].
+#[sealed::sealed]
+pub trait ThenResult<E> {
+    /// Returns [`Err`] if the predicate is `true`, and [`Ok(())`](Ok) if it is `false`.
+    ///
+    /// Note `err` is eagerly evaluated, for a lazily evaluated version see
+    /// [`ThenResult::then_err_with`].
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`NestedError::Inner`] with `err` if the predicate is `true`.
+    /// - Returns [`NestedError::Outer`] if the predicate failed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::ThenResult;
+    /// use fluent_result::nested::NestedError;
+    ///
+    /// let exists: Result<bool, &str> = Ok(true);
+    /// assert_eq!(exists.then_err("already exists"), Err(NestedError::Inner("already exists")));
+    ///
+    /// let exists: Result<bool, &str> = Err("io error");
+    /// assert_eq!(exists.then_err("already exists"), Err(NestedError::Outer("io error")));
+    ///
+    /// let exists: Result<bool, &str> = Ok(false);
+    /// assert_eq!(exists.then_err("already exists"), Ok(()));
+    /// ```
+    fn then_err<E2>(self, err: E2) -> Result<(), NestedError<E2, E>>;
+
+    /// Runs `factory` to create an [`Err`] value and returns it if the predicate is `true`, and
+    /// returns [`Ok(())`](Ok) if it is `false`.
+    ///
+    /// Note `factory` is lazily evaluated, for an eagerly evaluated version see
+    /// [`ThenResult::then_err`].
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`NestedError::Inner`] with the value of `factory` if the predicate is `true`.
+    /// - Returns [`NestedError::Outer`] if the predicate failed.
+    fn then_err_with<E2>(self, factory: impl FnOnce() -> E2) -> Result<(), NestedError<E2, E>>;
+
+    /// Converts the predicate into a [`Result`] by returning [`Ok(on_true)`](Ok) if it is `true`,
+    /// and [`Err(on_false)`](Err) if it is `false`.
+    ///
+    /// Note these values are eagerly evaluated, for a lazily evaluated version see
+    /// [`ThenResult::to_result_with`].
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`NestedError::Inner`] with `on_false` if the predicate is `false`.
+    /// - Returns [`NestedError::Outer`] if the predicate failed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::ThenResult;
+    /// use fluent_result::nested::NestedError;
+    ///
+    /// fn is_adult(age: &str) -> Result<bool, std::num::ParseIntError> {
+    ///     age.parse::<u32>().map(|age| age >= 18)
+    /// }
+    ///
+    /// assert_eq!(is_adult("21").to_result("welcome", "too young"), Ok("welcome"));
+    /// assert_eq!(is_adult("16").to_result("welcome", "too young"), Err(NestedError::Inner("too young")));
+    /// assert!(is_adult("abc").to_result("welcome", "too young").is_err_and(|err| err.is_outer()));
+    /// ```
+    fn to_result<T, E2>(self, on_true: T, on_false: E2) -> Result<T, NestedError<E2, E>>;
+
+    /// Converts the predicate into a [`Result`] by running `on_true` to create an [`Ok`] value if
+    /// it is `true`, and running `on_false` to create an [`Err`] value if it is `false`.
+    ///
+    /// Note these values are lazily evaluated, for an eagerly evaluated version see
+    /// [`ThenResult::to_result`].
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`NestedError::Inner`] with the value of `on_false` if the predicate is `false`.
+    /// - Returns [`NestedError::Outer`] if the predicate failed.
+    fn to_result_with<T, E2>(
+        self,
+        on_true: impl FnOnce() -> T,
+        on_false: impl FnOnce() -> E2,
+    ) -> Result<T, NestedError<E2, E>>;
+
+    /// Returns [`Ok(None)`](Ok) if the predicate is `true`, and [`Ok(Some(()))`](Ok) if it is
+    /// `false`.
+    ///
+    /// Both layers can be unwrapped with `?` in a function returning `Result<Option<_>, E>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the predicate's error if it failed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::ThenResult;
+    ///
+    /// let is_empty: Result<bool, &str> = Ok(true);
+    /// assert_eq!(is_empty.then_none(), Ok(None));
+    ///
+    /// let is_empty: Result<bool, &str> = Ok(false);
+    /// assert_eq!(is_empty.then_none(), Ok(Some(())));
+    ///
+    /// let is_empty: Result<bool, &str> = Err("io error");
+    /// assert_eq!(is_empty.then_none(), Err("io error"));
+    /// ```
+    fn then_none(self) -> Result<Option<()>, E>;
+}
+
+/// The implementation of [`ThenResult`] for `Result<bool, E>`.
+#[sealed::sealed]
+impl<E> ThenResult<E> for Result<bool, E> {
+    #[inline]
+    fn then_err<E2>(self, err: E2) -> Result<(), NestedError<E2, E>> {
+        match self {
+            Ok(true) => Err(NestedError::Inner(err)),
+            Ok(false) => Ok(()),
+            Err(e) => Err(NestedError::Outer(e)),
+        }
+    }
+
+    #[inline]
+    fn then_err_with<E2>(self, factory: impl FnOnce() -> E2) -> Result<(), NestedError<E2, E>> {
+        match self {
+            Ok(true) => Err(NestedError::Inner(factory())),
+            Ok(false) => Ok(()),
+            Err(e) => Err(NestedError::Outer(e)),
+        }
+    }
+
+    #[inline]
+    fn to_result<T, E2>(self, on_true: T, on_false: E2) -> Result<T, NestedError<E2, E>> {
+        match self {
+            Ok(true) => Ok(on_true),
+            Ok(false) => Err(NestedError::Inner(on_false)),
+            Err(e) => Err(NestedError::Outer(e)),
+        }
+    }
+
+    #[inline]
+    fn to_result_with<T, E2>(
+        self,
+        on_true: impl FnOnce() -> T,
+        on_false: impl FnOnce() -> E2,
+    ) -> Result<T, NestedError<E2, E>> {
+        match self {
+            Ok(true) => Ok(on_true()),
+            Ok(false) => Err(NestedError::Inner(on_false())),
+            Err(e) => Err(NestedError::Outer(e)),
+        }
+    }
+
+    #[inline]
+    fn then_none(self) -> Result<Option<()>, E> {
+        self.map(|value| if value { None } else { Some(()) })
+    }
+}
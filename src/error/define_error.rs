@@ -0,0 +1,145 @@
+/// Defines error types with fixed messages, implementing [`Debug`], [`Display`](core::fmt::Display)
+/// and [`Error`](core::error::Error).
+///
+/// Three forms are supported, and any number of them can be defined in one invocation:
+///
+/// - `struct Name => "message";` defines a unit struct, which also derives [`Clone`], [`Copy`],
+///   [`PartialEq`], [`Eq`], [`Hash`] and [`Default`].
+/// - `struct Name(Source) => "message";` defines a tuple struct wrapping a source error, which is
+///   returned by [`Error::source`](core::error::Error::source). [`From<Source>`](From) is
+///   implemented.
+/// - `enum Name { Variant => "message", Variant(Source) => "message" }` defines an enum whose
+///   variants optionally wrap a source error. For each variant with a source, [`From<Source>`](From)
+///   is implemented, so each source type may only be used once per enum.
+///
+/// Attributes, such as doc comments or additional derives, and visibility are passed through.
+/// Sources must implement [`Error`](core::error::Error) and be `'static`. The generated types
+/// work with [`FlattenErr`](crate::nested::FlattenErr), [`BoxErr`](crate::nested::BoxErr) and
+/// [`NestedError`](crate::nested::NestedError), like any other error.
+///
+/// # Example
+///
+/// ```rust
+/// use std::error::Error;
+/// use fluent_result::bool::Then;
+/// use fluent_result::error::define_error;
+///
+/// define_error! {
+///     /// The configuration file was empty.
+///     pub struct EmptyConfig => "config is empty";
+///
+///     /// Loading the configuration failed.
+///     pub enum ConfigError {
+///         /// The configuration file was empty.
+///         Empty(EmptyConfig) => "invalid config",
+///         /// The port was missing.
+///         MissingPort => "port is missing",
+///     }
+/// }
+///
+/// fn load(config: &str) -> Result<u16, ConfigError> {
+///     config.is_empty().then_err(EmptyConfig)?;
+///     config.parse().ok().ok_or(ConfigError::MissingPort)
+/// }
+///
+/// let err = load("").unwrap_err();
+/// assert_eq!(err.to_string(), "invalid config");
+/// assert_eq!(err.source().map(ToString::to_string), Some("config is empty".to_string()));
+/// assert_eq!(load("port").unwrap_err().to_string(), "port is missing");
+/// ```
+#[macro_export]
+macro_rules! define_error {
+    () => {};
+    ($(#[$meta:meta])* $vis:vis struct $name:ident => $msg:literal; $($rest:tt)*) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        $vis struct $name;
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str($msg)
+            }
+        }
+
+        impl ::core::error::Error for $name {}
+
+        $crate::define_error!($($rest)*);
+    };
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($source_vis:vis $source:ty) => $msg:literal; $($rest:tt)*) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        $vis struct $name($source_vis $source);
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str($msg)
+            }
+        }
+
+        impl ::core::error::Error for $name {
+            fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> {
+                ::core::option::Option::Some(&self.0)
+            }
+        }
+
+        impl ::core::convert::From<$source> for $name {
+            fn from(source: $source) -> Self {
+                Self(source)
+            }
+        }
+
+        $crate::define_error!($($rest)*);
+    };
+    (
+        $(#[$meta:meta])* $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident $(($source:ty))? => $msg:literal),* $(,)?
+        }
+        $($rest:tt)*
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant $(($source))?,)*
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $(Self::$variant { .. } => f.write_str($msg),)*
+                }
+            }
+        }
+
+        impl ::core::error::Error for $name {
+            #[allow(irrefutable_let_patterns)]
+            fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> {
+                $($crate::__define_error_variant!(source, self, $variant $(, $source)?);)*
+                ::core::option::Option::None
+            }
+        }
+
+        $($crate::__define_error_variant!(from, $name, $variant $(, $source)?);)*
+
+        $crate::define_error!($($rest)*);
+    };
+}
+
+/// Generates the per-variant parts of an enum defined by [`define_error!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_error_variant {
+    (source, $self:ident, $variant:ident) => {};
+    (source, $self:ident, $variant:ident, $source:ty) => {
+        if let Self::$variant(source) = $self {
+            return ::core::option::Option::Some(source);
+        }
+    };
+    (from, $name:ident, $variant:ident) => {};
+    (from, $name:ident, $variant:ident, $source:ty) => {
+        impl ::core::convert::From<$source> for $name {
+            fn from(source: $source) -> Self {
+                Self::$variant(source)
+            }
+        }
+    };
+}
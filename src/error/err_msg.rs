@@ -0,0 +1,84 @@
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+use crate::error::MessageError;
+
+/// An extension trait for [`Option`] that converts it to a [`Result`] with a [`MessageError`].
+#[sealed::sealed]
+pub trait OkOrMsg<T> {
+    /// Returns the value as [`Ok`], or a [`MessageError`] with `msg` if it is [`None`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MessageError(msg)`](MessageError) if the value is [`None`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::error::{MessageError, OkOrMsg};
+    ///
+    /// assert_eq!(Some(42).ok_or_msg("missing"), Ok(42));
+    /// assert_eq!(None::<u32>.ok_or_msg("missing"), Err(MessageError("missing")));
+    /// ```
+    fn ok_or_msg(self, msg: &'static str) -> Result<T, MessageError>;
+}
+
+#[sealed::sealed]
+impl<T> OkOrMsg<T> for Option<T> {
+    #[inline]
+    fn ok_or_msg(self, msg: &'static str) -> Result<T, MessageError> {
+        self.ok_or(MessageError(msg))
+    }
+}
+
+/// An extension trait for [`Result`]s with a message as the error, such as
+/// `Result<T, &'static str>`, that wraps the message in a [`MessageError`].
+///
+/// Implemented for `&'static str` messages and, with the `alloc` feature, for `String` and
+/// `Cow<'static, str>` messages. Errors that already implement [`Error`](core::error::Error) do
+/// not need wrapping.
+#[sealed::sealed]
+pub trait ErrMsg<T, M> {
+    /// Wraps the error message in a [`MessageError`], making it an [`Error`](core::error::Error).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MessageError`] with the message if the value is [`Err`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::bool::Then;
+    /// use fluent_result::error::{ErrMsg, MessageError};
+    ///
+    /// let result = true.then_err("number is even").err_msg();
+    /// assert_eq!(result, Err(MessageError("number is even")));
+    /// ```
+    fn err_msg(self) -> Result<T, MessageError<M>>;
+}
+
+#[sealed::sealed]
+impl<T> ErrMsg<T, &'static str> for Result<T, &'static str> {
+    #[inline]
+    fn err_msg(self) -> Result<T, MessageError> {
+        self.map_err(MessageError)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[sealed::sealed]
+impl<T> ErrMsg<T, String> for Result<T, String> {
+    #[inline]
+    fn err_msg(self) -> Result<T, MessageError<String>> {
+        self.map_err(MessageError)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[sealed::sealed]
+impl<T> ErrMsg<T, Cow<'static, str>> for Result<T, Cow<'static, str>> {
+    #[inline]
+    fn err_msg(self) -> Result<T, MessageError<Cow<'static, str>>> {
+        self.map_err(MessageError)
+    }
+}
@@ -0,0 +1,82 @@
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+/// An error consisting only of a message.
+///
+/// `&'static str` does not implement [`Error`], so a `Result<T, &'static str>`, such as one from
+/// `then_err("message")`, cannot be used with [`BoxErr`](crate::nested::BoxErr) or the [`Error`]
+/// implementation of [`NestedError`](crate::nested::NestedError). Wrapping the message in a
+/// [`MessageError`] makes it an [`Error`].
+///
+/// By default the message is a `&'static str`. With the `alloc` feature,
+/// `MessageError<Cow<'static, str>>` can hold either a static or an owned message.
+///
+/// # Example
+///
+/// ```rust
+/// use std::error::Error;
+/// use fluent_result::bool::Then;
+/// use fluent_result::error::MessageError;
+///
+/// fn reject_even(number: u32) -> Result<u32, Box<dyn Error>> {
+///     (number % 2 == 0).then_msg_err("number is even")?;
+///     Ok(number)
+/// }
+///
+/// assert_eq!(reject_even(2).unwrap_err().to_string(), "number is even");
+/// assert_eq!(MessageError::new("oops").to_string(), "oops");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct MessageError<M = &'static str>(pub M);
+
+impl<M> MessageError<M> {
+    /// Creates a new [`MessageError`] with `message`.
+    #[must_use]
+    pub const fn new(message: M) -> Self {
+        Self(message)
+    }
+
+    /// The message of the error.
+    #[must_use]
+    pub const fn message(&self) -> &M {
+        &self.0
+    }
+}
+
+impl<M: Display> Display for MessageError<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<M: Display + Debug> Error for MessageError<M> {}
+
+impl From<&'static str> for MessageError {
+    fn from(message: &'static str) -> Self {
+        Self(message)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&'static str> for MessageError<Cow<'static, str>> {
+    fn from(message: &'static str) -> Self {
+        Self(Cow::Borrowed(message))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for MessageError<Cow<'static, str>> {
+    fn from(message: String) -> Self {
+        Self(Cow::Owned(message))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<MessageError> for MessageError<Cow<'static, str>> {
+    fn from(error: MessageError) -> Self {
+        Self(Cow::Borrowed(error.0))
+    }
+}
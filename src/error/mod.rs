@@ -0,0 +1,11 @@
+mod define_error;
+mod err_msg;
+mod message_error;
+#[cfg(feature = "alloc")]
+mod multi_error;
+
+pub use crate::define_error;
+pub use err_msg::{ErrMsg, OkOrMsg};
+pub use message_error::MessageError;
+#[cfg(feature = "alloc")]
+pub use multi_error::MultiError;
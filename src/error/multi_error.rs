@@ -0,0 +1,116 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+use alloc::vec::Vec;
+
+/// An error made up of several errors, such as every error of a batch.
+///
+/// Displays as a numbered list of the errors. A `Vec<E>` converts into a [`MultiError`] with
+/// [`From`], so the errors returned by [`CollectAll`](crate::into::CollectAll) can be propagated
+/// with `?`. It is also returned by [`Checks::finish`](crate::bool::Checks::finish) and
+/// [`SoftAssert::finish`](crate::expect::SoftAssert::finish).
+///
+/// # Example
+///
+/// ```rust
+/// use std::num::ParseIntError;
+/// use fluent_result::error::MultiError;
+/// use fluent_result::into::CollectAll;
+///
+/// fn parse_all(inputs: &[&str]) -> Result<Vec<u32>, MultiError<ParseIntError>> {
+///     Ok(inputs.iter().map(|input| input.parse()).collect_all()?)
+/// }
+///
+/// assert_eq!(parse_all(&["1", "2"]), Ok(vec![1, 2]));
+///
+/// let err = parse_all(&["1", "x", ""]).unwrap_err();
+/// assert_eq!(err.len(), 2);
+/// assert_eq!(
+///     err.to_string(),
+///     "2 errors:\n    0: invalid digit found in string\n    1: cannot parse integer from empty string"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MultiError<E>(Vec<E>);
+
+impl<E> MultiError<E> {
+    /// Creates a new [`MultiError`] from `errors`.
+    #[must_use]
+    pub const fn new(errors: Vec<E>) -> Self {
+        Self(errors)
+    }
+
+    /// The errors.
+    #[must_use]
+    pub fn errors(&self) -> &[E] {
+        &self.0
+    }
+
+    /// The number of errors.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no errors.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the errors.
+    pub fn iter(&self) -> core::slice::Iter<'_, E> {
+        self.0.iter()
+    }
+}
+
+impl<E> IntoIterator for MultiError<E> {
+    type Item = E;
+    type IntoIter = alloc::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a MultiError<E> {
+    type Item = &'a E;
+    type IntoIter = core::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<E> From<Vec<E>> for MultiError<E> {
+    fn from(errors: Vec<E>) -> Self {
+        Self(errors)
+    }
+}
+
+impl<E> From<MultiError<E>> for Vec<E> {
+    fn from(error: MultiError<E>) -> Self {
+        error.0
+    }
+}
+
+impl<E> FromIterator<E> for MultiError<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<E: Display> Display for MultiError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.0.len() {
+            1 => write!(f, "1 error:")?,
+            len => write!(f, "{len} errors:")?,
+        }
+        for (index, error) in self.0.iter().enumerate() {
+            write!(f, "\n    {index}: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Error> Error for MultiError<E> {}
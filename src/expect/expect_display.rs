@@ -0,0 +1,158 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+/// An extension trait for [`Result`] that allows unwrapping the [`Ok`] variant, panicking with the
+/// [`Display`] of the error and its full [`source`](Error::source) chain.
+///
+/// [`Result::expect`] and [`Result::unwrap`] panic with the [`Debug`](core::fmt::Debug)
+/// representation of the error, which is often unreadable for end users and omits the cause
+/// chain. This trait always panics, regardless of build mode.
+///
+/// # Type Parameters
+///
+/// - `T`: The success type.
+/// - `E`: The error type whose chain is reported. For boxed errors this is the boxed
+///   `dyn Error`, which allows the trait to work with the results produced by
+///   [`BoxErr`](crate::nested::BoxErr).
+#[sealed::sealed]
+pub trait ExpectDisplay<T, E: ?Sized> {
+    /// Returns the contained [`Ok`] value, consuming the `self` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an [`Err`], with a panic message consisting of `msg` followed by the
+    /// [`Display`] of the error and every [`source`](Error::source) in its chain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::expect::ExpectDisplay;
+    ///
+    /// let result: Result<u32, std::fmt::Error> = Ok(42);
+    /// assert_eq!(result.expect_display("formatting failed"), 42);
+    /// ```
+    fn expect_display(self, msg: &str) -> T;
+
+    /// Returns the contained [`Ok`] value, consuming the `self` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is an [`Err`], with a fixed panic message followed by the [`Display`]
+    /// of the error and every [`source`](Error::source) in its chain.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::expect::ExpectDisplay;
+    ///
+    /// let result: Result<u32, std::fmt::Error> = Ok(42);
+    /// assert_eq!(result.unwrap_display(), 42);
+    /// ```
+    fn unwrap_display(self) -> T;
+}
+
+#[sealed::sealed]
+impl<T, E: Error> ExpectDisplay<T, E> for Result<T, E> {
+    #[inline]
+    #[track_caller]
+    fn expect_display(self, msg: &str) -> T {
+        self.unwrap_or_else(|err| panic!("{msg}: {}", ErrorReport::new(&err)))
+    }
+
+    #[inline]
+    #[track_caller]
+    fn unwrap_display(self) -> T {
+        self.unwrap_or_else(|err| {
+            panic!("called `Result::unwrap_display()` on an `Err` value: {}", ErrorReport::new(&err))
+        })
+    }
+}
+
+/// Implementation for the boxed errors produced by [`BoxErr`](crate::nested::BoxErr).
+#[cfg(feature = "alloc")]
+#[sealed::sealed]
+impl<T> ExpectDisplay<T, dyn Error> for Result<T, alloc::boxed::Box<dyn Error>> {
+    #[inline]
+    #[track_caller]
+    fn expect_display(self, msg: &str) -> T {
+        self.unwrap_or_else(|err| panic!("{msg}: {}", ErrorReport::new(err.as_ref())))
+    }
+
+    #[inline]
+    #[track_caller]
+    fn unwrap_display(self) -> T {
+        self.unwrap_or_else(|err| {
+            panic!("called `Result::unwrap_display()` on an `Err` value: {}", ErrorReport::new(err.as_ref()))
+        })
+    }
+}
+
+/// Implementation for thread-safe boxed errors.
+#[cfg(feature = "alloc")]
+#[sealed::sealed]
+impl<T> ExpectDisplay<T, dyn Error + Send + Sync> for Result<T, alloc::boxed::Box<dyn Error + Send + Sync>> {
+    #[inline]
+    #[track_caller]
+    fn expect_display(self, msg: &str) -> T {
+        self.unwrap_or_else(|err| panic!("{msg}: {}", ErrorReport::new(err.as_ref())))
+    }
+
+    #[inline]
+    #[track_caller]
+    fn unwrap_display(self) -> T {
+        self.unwrap_or_else(|err| {
+            panic!("called `Result::unwrap_display()` on an `Err` value: {}", ErrorReport::new(err.as_ref()))
+        })
+    }
+}
+
+/// Formats an [`Error`] followed by every [`source`](Error::source) in its chain.
+///
+/// The error itself is written on the first line, followed by a numbered `Caused by:` list if it
+/// has any sources.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::expect::ErrorReport;
+/// use fluent_result::nested::NestedError;
+///
+/// let error: NestedError<std::fmt::Error, std::fmt::Error> = NestedError::Inner(std::fmt::Error);
+/// let report = ErrorReport::new(&error).to_string();
+/// assert_eq!(report, "an error occurred when formatting an argument\n\nCaused by:\n    0: an error occurred when formatting an argument");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorReport<'a>(&'a dyn Error);
+
+impl<'a> ErrorReport<'a> {
+    /// Creates a new [`ErrorReport`] for `error`.
+    #[must_use]
+    pub const fn new(error: &'a dyn Error) -> Self {
+        Self(error)
+    }
+}
+
+impl ErrorReport<'_> {
+    /// Writes the [`source`](Error::source) chain of the error, without the error itself.
+    pub(crate) fn fmt_sources(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut source = self.0.source();
+        if source.is_some() {
+            write!(f, "\n\nCaused by:")?;
+        }
+
+        let mut index = 0;
+        while let Some(error) = source {
+            write!(f, "\n    {index}: {error}")?;
+            source = error.source();
+            index += 1;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ErrorReport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)?;
+        self.fmt_sources(f)
+    }
+}
@@ -0,0 +1,699 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+use derive_more::IsVariant;
+
+use crate::expect::mode::Mode;
+use crate::expect::{FailureKind, fail};
+
+/// An extension trait for iterators that allows asserting the number or uniqueness of items in a
+/// given [`Mode`].
+///
+/// For the common modes, see [`dbg::ExpectItems`] and
+/// [`rls::ExpectItems`]. For fallible versions, see [`TryItems`].
+///
+/// Assertions are checked by consuming the iterator, but never further than needed to decide
+/// them, so counting assertions also work on infinite iterators. If `M` is not enabled, the
+/// iterator is only consumed as far as needed to produce a result.
+#[sealed::sealed]
+pub trait ExpectItemsIn: IntoIterator + Sized {
+    /// Asserts that there are no items, if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and there are any items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::expect::ExpectItemsIn;
+    /// use fluent_result::expect::mode::Release;
+    ///
+    /// Vec::<u32>::new().assert_empty_in::<Release>();
+    /// ```
+    fn assert_empty_in<M: Mode>(self);
+
+    /// Asserts that there are no items with `msg` as the assertion message, if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if `M` is enabled and there are any items.
+    fn expect_empty_in<M: Mode>(self, msg: &str);
+
+    /// Returns the only item, asserting that there are no others if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items, regardless of `M` or the
+    /// [failure handler](crate::expect::set_failure_handler), since there is no item to return.
+    /// Panics if `M` is enabled and there is more than one item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::expect::ExpectItemsIn;
+    /// use fluent_result::expect::mode::Release;
+    ///
+    /// let rows = vec!["alice"];
+    /// assert_eq!(rows.assert_single_in::<Release>(), "alice");
+    /// ```
+    fn assert_single_in<M: Mode>(self) -> Self::Item;
+
+    /// Returns the only item, asserting that there are no others with `msg` as the assertion
+    /// message if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if there are no items, regardless of `M` or the
+    /// [failure handler](crate::expect::set_failure_handler), since there is no item to return.
+    /// Panics with `msg` if `M` is enabled and there is more than one item.
+    fn expect_single_in<M: Mode>(self, msg: &str) -> Self::Item;
+
+    /// Asserts that there are at most `max` items, if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and there are more than `max` items.
+    fn assert_at_most_in<M: Mode>(self, max: usize);
+
+    /// Asserts that there are at most `max` items with `msg` as the assertion message, if `M` is
+    /// enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if `M` is enabled and there are more than `max` items.
+    fn expect_at_most_in<M: Mode>(self, max: usize, msg: &str);
+
+    /// Asserts that no item is equal to another, if `M` is enabled.
+    ///
+    /// Each item is compared to every later item, by cloning the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and any two items are equal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::expect::ExpectItemsIn;
+    /// use fluent_result::expect::mode::Release;
+    ///
+    /// ["alice", "bob"].iter().assert_unique_in::<Release>();
+    /// ```
+    fn assert_unique_in<M: Mode>(self)
+    where
+        Self::IntoIter: Clone,
+        Self::Item: PartialEq;
+
+    /// Asserts that no item is equal to another with `msg` as the assertion message, if `M` is
+    /// enabled.
+    ///
+    /// Each item is compared to every later item, by cloning the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if `M` is enabled and any two items are equal.
+    fn expect_unique_in<M: Mode>(self, msg: &str)
+    where
+        Self::IntoIter: Clone,
+        Self::Item: PartialEq;
+}
+
+#[sealed::sealed]
+impl<I: IntoIterator> ExpectItemsIn for I {
+    #[inline]
+    #[track_caller]
+    fn assert_empty_in<M: Mode>(self) {
+        check::<M, _>(FailureKind::ExpectedEmpty, || self.try_empty(), None);
+    }
+
+    #[inline]
+    #[track_caller]
+    fn expect_empty_in<M: Mode>(self, msg: &str) {
+        check::<M, _>(FailureKind::ExpectedEmpty, || self.try_empty(), Some(msg));
+    }
+
+    #[inline]
+    #[track_caller]
+    fn assert_single_in<M: Mode>(self) -> Self::Item {
+        single::<M, _>(self.into_iter(), None)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn expect_single_in<M: Mode>(self, msg: &str) -> Self::Item {
+        single::<M, _>(self.into_iter(), Some(msg))
+    }
+
+    #[inline]
+    #[track_caller]
+    fn assert_at_most_in<M: Mode>(self, max: usize) {
+        check::<M, _>(FailureKind::ExpectedAtMost, || self.try_at_most(max), None);
+    }
+
+    #[inline]
+    #[track_caller]
+    fn expect_at_most_in<M: Mode>(self, max: usize, msg: &str) {
+        check::<M, _>(FailureKind::ExpectedAtMost, || self.try_at_most(max), Some(msg));
+    }
+
+    #[inline]
+    #[track_caller]
+    fn assert_unique_in<M: Mode>(self)
+    where
+        Self::IntoIter: Clone,
+        Self::Item: PartialEq,
+    {
+        check::<M, _>(FailureKind::ExpectedUnique, || self.try_unique(), None);
+    }
+
+    #[inline]
+    #[track_caller]
+    fn expect_unique_in<M: Mode>(self, msg: &str)
+    where
+        Self::IntoIter: Clone,
+        Self::Item: PartialEq,
+    {
+        check::<M, _>(FailureKind::ExpectedUnique, || self.try_unique(), Some(msg));
+    }
+}
+
+/// Runs `try_check` if `M` is enabled, reporting any error as a failure.
+#[track_caller]
+fn check<M: Mode, E: Display>(kind: FailureKind, try_check: impl FnOnce() -> Result<(), E>, msg: Option<&str>) {
+    if !M::ENABLED {
+        return;
+    }
+
+    if let Err(err) = try_check() {
+        let message = CheckMessage { err, msg };
+        fail(kind, format_args!("{message}"));
+    }
+}
+
+/// Returns the first item of `iter`, reporting a failure if it is not the only one and `M` is
+/// enabled.
+#[track_caller]
+fn single<M: Mode, I: Iterator>(mut iter: I, msg: Option<&str>) -> I::Item {
+    let Some(first) = iter.next() else {
+        let message = CheckMessage { err: CountError::TooFew { min: 1, actual: 0 }, msg };
+        fail(FailureKind::ExpectedSingle, format_args!("{message}"));
+        panic!("{message}");
+    };
+
+    if M::ENABLED && iter.next().is_some() {
+        let message = CheckMessage { err: too_many(1, 2, &iter), msg };
+        fail(FailureKind::ExpectedSingle, format_args!("{message}"));
+    }
+    first
+}
+
+/// Creates a [`CountError::TooMany`] after `counted` items, adding the items `rest` is known to
+/// have left without consuming them.
+fn too_many<I: Iterator>(max: usize, counted: usize, rest: &I) -> CountError {
+    CountError::TooMany { max, at_least: counted.saturating_add(rest.size_hint().0) }
+}
+
+/// The failure message for an error, `msg` or a fixed message followed by the error.
+struct CheckMessage<'a, E> {
+    err: E,
+    msg: Option<&'a str>,
+}
+
+impl<E: Display> Display for CheckMessage<'_, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.msg {
+            Some(msg) => write!(f, "{msg}: {}", self.err),
+            None => write!(f, "assertion failed: {}", self.err),
+        }
+    }
+}
+
+/// An extension trait for iterators that allows fallibly checking the number or uniqueness of
+/// items.
+///
+/// For asserting versions, see [`ExpectItemsIn`]. Counting stops after the first item too many, so
+/// a [`CountError::TooMany`] reports a lower bound of the count. The bound includes the remaining
+/// items the iterator knows of, so it is exact for an [`ExactSizeIterator`].
+#[sealed::sealed]
+pub trait TryItems: IntoIterator + Sized {
+    /// Checks that there are no items.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CountError::TooMany`] if there are any items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::expect::{CountError, TryItems};
+    ///
+    /// assert_eq!(Vec::<u32>::new().try_empty(), Ok(()));
+    /// assert_eq!(vec![1, 2].try_empty(), Err(CountError::TooMany { max: 0, at_least: 2 }));
+    /// ```
+    fn try_empty(self) -> Result<(), CountError>;
+
+    /// Returns the only item.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CountError::TooFew`] if there are no items, or [`CountError::TooMany`] if there
+    /// is more than one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::expect::{CountError, TryItems};
+    ///
+    /// assert_eq!(vec![1].try_single(), Ok(1));
+    /// assert_eq!(Vec::<u32>::new().try_single(), Err(CountError::TooFew { min: 1, actual: 0 }));
+    /// assert_eq!(vec![1, 2, 3].try_single(), Err(CountError::TooMany { max: 1, at_least: 3 }));
+    /// assert_eq!((0..).filter(|n| n % 2 == 0).try_single(), Err(CountError::TooMany { max: 1, at_least: 2 }));
+    /// ```
+    fn try_single(self) -> Result<Self::Item, CountError>;
+
+    /// Checks that there are at most `max` items.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CountError::TooMany`] if there are more than `max` items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::expect::{CountError, TryItems};
+    ///
+    /// assert_eq!(vec![1, 2].try_at_most(2), Ok(()));
+    /// assert_eq!(vec![1, 2, 3, 4].try_at_most(2), Err(CountError::TooMany { max: 2, at_least: 4 }));
+    /// assert_eq!((0..).filter(|n| n % 2 == 0).try_at_most(2), Err(CountError::TooMany { max: 2, at_least: 3 }));
+    /// ```
+    fn try_at_most(self, max: usize) -> Result<(), CountError>;
+
+    /// Checks that no item is equal to another.
+    ///
+    /// Each item is compared to every later item, by cloning the iterator.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DuplicateError`] with the indices of the first pair of equal items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::expect::{DuplicateError, TryItems};
+    ///
+    /// assert_eq!(["a", "b", "c"].iter().try_unique(), Ok(()));
+    /// assert_eq!(["a", "b", "a"].iter().try_unique(), Err(DuplicateError { first: 0, second: 2 }));
+    /// ```
+    fn try_unique(self) -> Result<(), DuplicateError>
+    where
+        Self::IntoIter: Clone,
+        Self::Item: PartialEq;
+}
+
+#[sealed::sealed]
+impl<I: IntoIterator> TryItems for I {
+    #[inline]
+    fn try_empty(self) -> Result<(), CountError> {
+        self.try_at_most(0)
+    }
+
+    #[inline]
+    fn try_single(self) -> Result<Self::Item, CountError> {
+        let mut iter = self.into_iter();
+        match (iter.next(), iter.next()) {
+            (Some(first), None) => Ok(first),
+            (Some(_), Some(_)) => Err(too_many(1, 2, &iter)),
+            (None, _) => Err(CountError::TooFew { min: 1, actual: 0 }),
+        }
+    }
+
+    #[inline]
+    fn try_at_most(self, max: usize) -> Result<(), CountError> {
+        let mut iter = self.into_iter();
+        match iter.by_ref().take(max.saturating_add(1)).count() {
+            counted if counted > max => Err(too_many(max, counted, &iter)),
+            _ => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn try_unique(self) -> Result<(), DuplicateError>
+    where
+        Self::IntoIter: Clone,
+        Self::Item: PartialEq,
+    {
+        let mut iter = self.into_iter();
+        let mut first = 0;
+        while let Some(item) = iter.next() {
+            if let Some(offset) = iter.clone().position(|other| other == item) {
+                return Err(DuplicateError { first, second: first + 1 + offset });
+            }
+            first += 1;
+        }
+        Ok(())
+    }
+}
+
+/// An error for an unexpected number of items, created by [`TryItems`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, IsVariant)]
+pub enum CountError {
+    /// There were fewer items than required.
+    TooFew {
+        /// The minimum number of items.
+        min: usize,
+        /// The number of items observed.
+        actual: usize,
+    },
+    /// There were more items than allowed.
+    TooMany {
+        /// The maximum number of items.
+        max: usize,
+        /// A lower bound of the number of items, since counting stops after the first item too
+        /// many. It is exact if the iterator knows how many items it has left.
+        at_least: usize,
+    },
+}
+
+impl CountError {
+    /// The number of items observed.
+    ///
+    /// For [`CountError::TooMany`] this is a lower bound, see its `at_least` field.
+    #[must_use]
+    pub const fn actual(&self) -> usize {
+        match self {
+            Self::TooFew { actual, .. } => *actual,
+            Self::TooMany { at_least, .. } => *at_least,
+        }
+    }
+}
+
+impl Display for CountError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let items = |count| if count == 1 { "item" } else { "items" };
+        match *self {
+            Self::TooFew { min, actual } => write!(f, "expected at least {min} {} but found {actual}", items(min)),
+            Self::TooMany { max, at_least } => {
+                write!(f, "expected at most {max} {} but found at least {at_least}", items(max))
+            }
+        }
+    }
+}
+
+impl Error for CountError {}
+
+/// An error for two equal items, created by [`TryItems::try_unique`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DuplicateError {
+    /// The index of the first of the equal items.
+    pub first: usize,
+    /// The index of the second of the equal items.
+    pub second: usize,
+}
+
+impl Display for DuplicateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected unique items but item {} equals item {}", self.second, self.first)
+    }
+}
+
+impl Error for DuplicateError {}
+
+/// Debug-only [`debug_assert!`]s for iterators.
+pub mod dbg {
+    use super::ExpectItemsIn;
+    use crate::expect::mode::Debug;
+
+    /// An extension trait for iterators that allows [`debug_assert!`]ing the number of items.
+    ///
+    /// This is equivalent to [`ExpectItemsIn`] in [`Debug`](struct@Debug) mode.
+    #[sealed::sealed]
+    pub trait ExpectItems: ExpectItemsIn {
+        /// [`debug_assert!`]s that there are no items.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds if there are any items.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use fluent_result::expect::dbg::ExpectItems;
+        ///
+        /// Vec::<u32>::new().assert_empty();
+        /// ```
+        #[inline]
+        #[track_caller]
+        fn assert_empty(self) {
+            self.assert_empty_in::<Debug>();
+        }
+
+        /// [`debug_assert!`]s that there are no items with `msg` as the assertion message.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds with `msg` if there are any items.
+        #[inline]
+        #[track_caller]
+        fn expect_empty(self, msg: &str) {
+            self.expect_empty_in::<Debug>(msg);
+        }
+
+        /// Returns the only item, [`debug_assert!`]ing that there are no others.
+        ///
+        /// # Panics
+        ///
+        /// Panics if there are no items, in all build modes. Panics in debug builds if there is
+        /// more than one item.
+        #[inline]
+        #[track_caller]
+        fn assert_single(self) -> Self::Item {
+            self.assert_single_in::<Debug>()
+        }
+
+        /// Returns the only item, [`debug_assert!`]ing that there are no others with `msg` as the
+        /// assertion message.
+        ///
+        /// # Panics
+        ///
+        /// Panics with `msg` if there are no items, in all build modes. Panics in debug builds
+        /// with `msg` if there is more than one item.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use fluent_result::expect::dbg::ExpectItems;
+        ///
+        /// let rows = vec!["alice"];
+        /// assert_eq!(rows.expect_single("exactly one row should match"), "alice");
+        /// ```
+        #[inline]
+        #[track_caller]
+        fn expect_single(self, msg: &str) -> Self::Item {
+            self.expect_single_in::<Debug>(msg)
+        }
+
+        /// [`debug_assert!`]s that there are at most `max` items.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds if there are more than `max` items.
+        #[inline]
+        #[track_caller]
+        fn assert_at_most(self, max: usize) {
+            self.assert_at_most_in::<Debug>(max);
+        }
+
+        /// [`debug_assert!`]s that there are at most `max` items with `msg` as the assertion
+        /// message.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds with `msg` if there are more than `max` items.
+        #[inline]
+        #[track_caller]
+        fn expect_at_most(self, max: usize, msg: &str) {
+            self.expect_at_most_in::<Debug>(max, msg);
+        }
+
+        /// [`debug_assert!`]s that no item is equal to another.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds if any two items are equal.
+        #[inline]
+        #[track_caller]
+        fn assert_unique(self)
+        where
+            Self::IntoIter: Clone,
+            Self::Item: PartialEq,
+        {
+            self.assert_unique_in::<Debug>();
+        }
+
+        /// [`debug_assert!`]s that no item is equal to another with `msg` as the assertion message.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds with `msg` if any two items are equal.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use fluent_result::expect::dbg::ExpectItems;
+        ///
+        /// let ids = [1, 2, 3];
+        /// ids.iter().expect_unique("ids should not repeat");
+        /// ```
+        #[inline]
+        #[track_caller]
+        fn expect_unique(self, msg: &str)
+        where
+            Self::IntoIter: Clone,
+            Self::Item: PartialEq,
+        {
+            self.expect_unique_in::<Debug>(msg);
+        }
+    }
+
+    #[sealed::sealed]
+    impl<I: IntoIterator> ExpectItems for I {}
+}
+
+/// Release-mode [`assert!`]s for iterators.
+pub mod rls {
+    use super::ExpectItemsIn;
+    use crate::expect::mode::Release;
+
+    /// An extension trait for iterators that allows [`assert!`]ing the number of items.
+    ///
+    /// This is equivalent to [`ExpectItemsIn`] in [`Release`] mode.
+    #[sealed::sealed]
+    pub trait ExpectItems: ExpectItemsIn {
+        /// [`assert!`]s that there are no items.
+        ///
+        /// # Panics
+        ///
+        /// Panics if there are any items.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use fluent_result::expect::rls::ExpectItems;
+        ///
+        /// Vec::<u32>::new().assert_empty();
+        /// ```
+        #[inline]
+        #[track_caller]
+        fn assert_empty(self) {
+            self.assert_empty_in::<Release>();
+        }
+
+        /// [`assert!`]s that there are no items with `msg` as the assertion message.
+        ///
+        /// # Panics
+        ///
+        /// Panics with `msg` if there are any items.
+        #[inline]
+        #[track_caller]
+        fn expect_empty(self, msg: &str) {
+            self.expect_empty_in::<Release>(msg);
+        }
+
+        /// Returns the only item, [`assert!`]ing that there are no others.
+        ///
+        /// # Panics
+        ///
+        /// Panics if there are no items, or more than one item.
+        #[inline]
+        #[track_caller]
+        fn assert_single(self) -> Self::Item {
+            self.assert_single_in::<Release>()
+        }
+
+        /// Returns the only item, [`assert!`]ing that there are no others with `msg` as the
+        /// assertion message.
+        ///
+        /// # Panics
+        ///
+        /// Panics with `msg` if there are no items, or more than one item.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use fluent_result::expect::rls::ExpectItems;
+        ///
+        /// let rows = vec!["alice"];
+        /// assert_eq!(rows.expect_single("exactly one row should match"), "alice");
+        /// ```
+        #[inline]
+        #[track_caller]
+        fn expect_single(self, msg: &str) -> Self::Item {
+            self.expect_single_in::<Release>(msg)
+        }
+
+        /// [`assert!`]s that there are at most `max` items.
+        ///
+        /// # Panics
+        ///
+        /// Panics if there are more than `max` items.
+        #[inline]
+        #[track_caller]
+        fn assert_at_most(self, max: usize) {
+            self.assert_at_most_in::<Release>(max);
+        }
+
+        /// [`assert!`]s that there are at most `max` items with `msg` as the assertion message.
+        ///
+        /// # Panics
+        ///
+        /// Panics with `msg` if there are more than `max` items.
+        #[inline]
+        #[track_caller]
+        fn expect_at_most(self, max: usize, msg: &str) {
+            self.expect_at_most_in::<Release>(max, msg);
+        }
+
+        /// [`assert!`]s that no item is equal to another.
+        ///
+        /// # Panics
+        ///
+        /// Panics if any two items are equal.
+        #[inline]
+        #[track_caller]
+        fn assert_unique(self)
+        where
+            Self::IntoIter: Clone,
+            Self::Item: PartialEq,
+        {
+            self.assert_unique_in::<Release>();
+        }
+
+        /// [`assert!`]s that no item is equal to another with `msg` as the assertion message.
+        ///
+        /// # Panics
+        ///
+        /// Panics with `msg` if any two items are equal.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use fluent_result::expect::rls::ExpectItems;
+        ///
+        /// let ids = [1, 2, 3];
+        /// ids.iter().expect_unique("ids should not repeat");
+        /// ```
+        #[inline]
+        #[track_caller]
+        fn expect_unique(self, msg: &str)
+        where
+            Self::IntoIter: Clone,
+            Self::Item: PartialEq,
+        {
+            self.expect_unique_in::<Release>(msg);
+        }
+    }
+
+    #[sealed::sealed]
+    impl<I: IntoIterator> ExpectItems for I {}
+}
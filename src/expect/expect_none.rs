@@ -1,11 +1,68 @@
+use crate::expect::mode::Mode;
+use crate::expect::{FailureKind, fail};
+
+/// An extension trait for [`Option`] that allows asserting the existance of the [`None`] variant
+/// in a given [`Mode`].
+///
+/// For the common modes, see [`dbg::ExpectNone`] and [`rls::ExpectNone`].
+#[sealed::sealed]
+pub trait ExpectNoneIn {
+    /// Asserts a [`Option`] is [`None`], if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and the value is a [`Some`] variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fluent_result::expect::ExpectNoneIn;
+    /// use fluent_result::expect::mode::Release;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut users = HashMap::new();
+    /// users.insert("Alice", 1).assert_none_in::<Release>();
+    /// ```
+    fn assert_none_in<M: Mode>(self);
+
+    /// Asserts a [`Option`] is [`None`] with `msg`, if `M` is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if `M` is enabled and the value is a [`Some`] variant.
+    fn expect_none_in<M: Mode>(self, msg: &str);
+}
+
+#[sealed::sealed]
+impl<T> ExpectNoneIn for Option<T> {
+    #[inline]
+    #[track_caller]
+    fn assert_none_in<M: Mode>(self) {
+        if M::ENABLED && self.is_some() {
+            fail(FailureKind::ExpectedNone, format_args!("called `Option::assert_none()` on a `Some` value"));
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn expect_none_in<M: Mode>(self, msg: &str) {
+        if M::ENABLED && self.is_some() {
+            fail(FailureKind::ExpectedNone, format_args!("{msg}"));
+        }
+    }
+}
+
 /// Debug-only [`debug_assert!`] for [`Option`] values.
 pub mod dbg {
+    use super::ExpectNoneIn;
+    use crate::expect::mode::Debug;
+
     /// An extension trait for [`Option`] that allows [`debug_assert!`]ing the existance of the [`None`] variant.
     ///
     /// This trait only panics in debug mode (`cfg(debug_assertions)`). In release mode, it does
-    /// nothing.
+    /// nothing. This is equivalent to [`ExpectNoneIn`] in [`Debug`](struct@Debug) mode.
     #[sealed::sealed]
-    pub trait ExpectNone {
+    pub trait ExpectNone: ExpectNoneIn + Sized {
         /// [`debug_assert!`] a [`Option`] is [`None`].
         ///
         /// This is useful for validating that a method that should return [`None`] does so. For
@@ -29,7 +86,11 @@ pub mod dbg {
         /// let mut users = HashMap::new();
         /// add_new_user(&mut users, "Alice", 1);
         /// ```
-        fn assert_none(self);
+        #[inline]
+        #[track_caller]
+        fn assert_none(self) {
+            self.assert_none_in::<Debug>();
+        }
 
         /// [`debug_assert!`]s a [`Option`] is [`None`] with `msg`.
         ///
@@ -54,30 +115,27 @@ pub mod dbg {
         /// let mut users = HashMap::new();
         /// add_new_user(&mut users, "Alice", 1);
         /// ```
-        fn expect_none(self, msg: &str);
-    }
-
-    #[sealed::sealed]
-    impl<T> ExpectNone for Option<T> {
-        #[inline]
-        #[track_caller]
-        fn assert_none(self) {
-            debug_assert!(self.is_none(), "called `Option::assert_none()` on a `Some` value");
-        }
-
         #[inline]
         #[track_caller]
         fn expect_none(self, msg: &str) {
-            debug_assert!(self.is_none(), "{}", msg);
+            self.expect_none_in::<Debug>(msg);
         }
     }
+
+    #[sealed::sealed]
+    impl<T> ExpectNone for Option<T> {}
 }
 
 /// Release-mode [`assert!`] for [`Option`] values.
 pub mod rls {
+    use super::ExpectNoneIn;
+    use crate::expect::mode::Release;
+
     /// An extension trait for [`Option<T>`] that allows [`assert!`]ing the existance of the [`None`] variant.
+    ///
+    /// This is equivalent to [`ExpectNoneIn`] in [`Release`] mode.
     #[sealed::sealed]
-    pub trait ExpectNone {
+    pub trait ExpectNone: ExpectNoneIn + Sized {
         /// [`assert!`]s a [`Option`] is [`None`].
         ///
         /// This is useful for validating that a method that should return [`None`] does so. For
@@ -101,7 +159,11 @@ pub mod rls {
         /// let mut users = HashMap::new();
         /// add_new_user(&mut users, "Alice", 1);
         /// ```
-        fn assert_none(self);
+        #[inline]
+        #[track_caller]
+        fn assert_none(self) {
+            self.assert_none_in::<Release>();
+        }
 
         /// [`assert!`]s a [`Option`] is [`None`] with `msg`.
         ///
@@ -126,21 +188,13 @@ pub mod rls {
         /// let mut users = HashMap::new();
         /// add_new_user(&mut users, "Alice", 1);
         /// ```
-        fn expect_none(self, msg: &str);
-    }
-
-    #[sealed::sealed]
-    impl<T> ExpectNone for Option<T> {
-        #[inline]
-        #[track_caller]
-        fn assert_none(self) {
-            assert!(self.is_none(), "called `Option::assert_none()` on a `Some` value");
-        }
-
         #[inline]
         #[track_caller]
         fn expect_none(self, msg: &str) {
-            assert!(self.is_none(), "{}", msg);
+            self.expect_none_in::<Release>(msg);
         }
     }
+
+    #[sealed::sealed]
+    impl<T> ExpectNone for Option<T> {}
 }
@@ -0,0 +1,324 @@
+use core::fmt::Debug;
+use core::ops::RangeBounds;
+
+use crate::expect::mode::Mode;
+use crate::expect::{FailureKind, fail};
+
+/// An extension trait for any value that allows asserting properties of the value in a given
+/// [`Mode`], passing the value through.
+///
+/// Since the value is returned, these assertions can be placed in the middle of a chain, for
+/// example in a builder or an iterator `map`. For the common modes, see
+/// [`dbg::ExpectThat`] and
+/// [`rls::ExpectThat`].
+#[sealed::sealed]
+pub trait ExpectThatIn: Sized {
+    /// Asserts that the value satisfies `predicate`, if `M` is enabled, returning the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and `predicate` returns `false`. The panic message includes the
+    /// [`Debug`] representation of the value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::expect::ExpectThatIn;
+    /// use fluent_result::expect::mode::Release;
+    ///
+    /// let even = 42.assert_that_in::<Release>(|n| n % 2 == 0);
+    /// assert_eq!(even, 42);
+    /// ```
+    #[must_use]
+    fn assert_that_in<M: Mode>(self, predicate: impl FnOnce(&Self) -> bool) -> Self
+    where
+        Self: Debug;
+
+    /// Asserts that the value satisfies `predicate` with `msg` as the assertion message, if `M` is
+    /// enabled, returning the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if `M` is enabled and `predicate` returns `false`.
+    #[must_use]
+    fn expect_that_in<M: Mode>(self, predicate: impl FnOnce(&Self) -> bool, msg: &str) -> Self;
+
+    /// Asserts that the value is equal to `expected`, if `M` is enabled, returning the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and the value is not equal to `expected`. The panic message
+    /// includes the [`Debug`] representation of both values.
+    #[must_use]
+    fn assert_eq_to_in<M: Mode, U>(self, expected: &U) -> Self
+    where
+        Self: PartialEq<U> + Debug,
+        U: Debug + ?Sized;
+
+    /// Asserts that the value is within `range`, if `M` is enabled, returning the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is enabled and the value is not within `range`. The panic message includes
+    /// the [`Debug`] representation of the value and the range.
+    #[must_use]
+    fn assert_within_in<M: Mode, R>(self, range: R) -> Self
+    where
+        Self: PartialOrd + Debug,
+        R: RangeBounds<Self> + Debug;
+}
+
+#[sealed::sealed]
+impl<T> ExpectThatIn for T {
+    #[inline]
+    #[track_caller]
+    fn assert_that_in<M: Mode>(self, predicate: impl FnOnce(&Self) -> bool) -> Self
+    where
+        Self: Debug,
+    {
+        if M::ENABLED && !predicate(&self) {
+            fail(
+                FailureKind::ExpectedPredicate,
+                format_args!("assertion failed: `{self:?}` did not satisfy the predicate"),
+            );
+        }
+        self
+    }
+
+    #[inline]
+    #[track_caller]
+    fn expect_that_in<M: Mode>(self, predicate: impl FnOnce(&Self) -> bool, msg: &str) -> Self {
+        if M::ENABLED && !predicate(&self) {
+            fail(FailureKind::ExpectedPredicate, format_args!("{msg}"));
+        }
+        self
+    }
+
+    #[inline]
+    #[track_caller]
+    fn assert_eq_to_in<M: Mode, U>(self, expected: &U) -> Self
+    where
+        Self: PartialEq<U> + Debug,
+        U: Debug + ?Sized,
+    {
+        if M::ENABLED && self != *expected {
+            fail(
+                FailureKind::ExpectedEqual,
+                format_args!("assertion failed: expected `{expected:?}` but was `{self:?}`"),
+            );
+        }
+        self
+    }
+
+    #[inline]
+    #[track_caller]
+    fn assert_within_in<M: Mode, R>(self, range: R) -> Self
+    where
+        Self: PartialOrd + Debug,
+        R: RangeBounds<Self> + Debug,
+    {
+        if M::ENABLED && !range.contains(&self) {
+            fail(
+                FailureKind::ExpectedInRange,
+                format_args!("assertion failed: expected a value in `{range:?}` but was `{self:?}`"),
+            );
+        }
+        self
+    }
+}
+
+/// Debug-only [`debug_assert!`]s for any value.
+pub mod dbg {
+    use core::fmt::Debug;
+    use core::ops::RangeBounds;
+
+    use super::ExpectThatIn;
+    use crate::expect::mode;
+
+    /// An extension trait for any value that allows [`debug_assert!`]ing properties of the value,
+    /// passing the value through.
+    ///
+    /// This is equivalent to [`ExpectThatIn`] in [`Debug`](mode::Debug) mode.
+    #[sealed::sealed]
+    pub trait ExpectThat: ExpectThatIn {
+        /// [`debug_assert!`]s that the value satisfies `predicate`, returning the value.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds if `predicate` returns `false`. The panic message includes the
+        /// [`Debug`] representation of the value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use fluent_result::expect::dbg::ExpectThat;
+        ///
+        /// let lengths: Vec<usize> = ["a", "bb"].iter().map(|s| s.len().assert_that(|n| *n > 0)).collect();
+        /// assert_eq!(lengths, [1, 2]);
+        /// ```
+        #[must_use]
+        #[inline]
+        #[track_caller]
+        fn assert_that(self, predicate: impl FnOnce(&Self) -> bool) -> Self
+        where
+            Self: Debug,
+        {
+            self.assert_that_in::<mode::Debug>(predicate)
+        }
+
+        /// [`debug_assert!`]s that the value satisfies `predicate` with `msg` as the assertion
+        /// message, returning the value.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds with `msg` if `predicate` returns `false`.
+        #[must_use]
+        #[inline]
+        #[track_caller]
+        fn expect_that(self, predicate: impl FnOnce(&Self) -> bool, msg: &str) -> Self {
+            self.expect_that_in::<mode::Debug>(predicate, msg)
+        }
+
+        /// [`debug_assert!`]s that the value is equal to `expected`, returning the value.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds if the value is not equal to `expected`.
+        #[must_use]
+        #[inline]
+        #[track_caller]
+        fn assert_eq_to<U>(self, expected: &U) -> Self
+        where
+            Self: PartialEq<U> + Debug,
+            U: Debug + ?Sized,
+        {
+            self.assert_eq_to_in::<mode::Debug, U>(expected)
+        }
+
+        /// [`debug_assert!`]s that the value is within `range`, returning the value.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds if the value is not within `range`.
+        #[must_use]
+        #[inline]
+        #[track_caller]
+        fn assert_within<R>(self, range: R) -> Self
+        where
+            Self: PartialOrd + Debug,
+            R: RangeBounds<Self> + Debug,
+        {
+            self.assert_within_in::<mode::Debug, R>(range)
+        }
+    }
+
+    #[sealed::sealed]
+    impl<T> ExpectThat for T {}
+}
+
+/// Release-mode [`assert!`]s for any value.
+pub mod rls {
+    use core::fmt::Debug;
+    use core::ops::RangeBounds;
+
+    use super::ExpectThatIn;
+    use crate::expect::mode::Release;
+
+    /// An extension trait for any value that allows [`assert!`]ing properties of the value,
+    /// passing the value through.
+    ///
+    /// This is equivalent to [`ExpectThatIn`] in [`Release`] mode.
+    #[sealed::sealed]
+    pub trait ExpectThat: ExpectThatIn {
+        /// [`assert!`]s that the value satisfies `predicate`, returning the value.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `predicate` returns `false`. The panic message includes the [`Debug`]
+        /// representation of the value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use fluent_result::expect::rls::ExpectThat;
+        ///
+        /// let port = "8080".parse::<u16>().unwrap().assert_that(|port| *port >= 1024);
+        /// assert_eq!(port, 8080);
+        /// ```
+        #[must_use]
+        #[inline]
+        #[track_caller]
+        fn assert_that(self, predicate: impl FnOnce(&Self) -> bool) -> Self
+        where
+            Self: Debug,
+        {
+            self.assert_that_in::<Release>(predicate)
+        }
+
+        /// [`assert!`]s that the value satisfies `predicate` with `msg` as the assertion message,
+        /// returning the value.
+        ///
+        /// # Panics
+        ///
+        /// Panics with `msg` if `predicate` returns `false`.
+        #[must_use]
+        #[inline]
+        #[track_caller]
+        fn expect_that(self, predicate: impl FnOnce(&Self) -> bool, msg: &str) -> Self {
+            self.expect_that_in::<Release>(predicate, msg)
+        }
+
+        /// [`assert!`]s that the value is equal to `expected`, returning the value.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the value is not equal to `expected`.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use fluent_result::expect::rls::ExpectThat;
+        ///
+        /// let name = String::from("alice").assert_eq_to("alice");
+        /// assert_eq!(name, "alice");
+        /// ```
+        #[must_use]
+        #[inline]
+        #[track_caller]
+        fn assert_eq_to<U>(self, expected: &U) -> Self
+        where
+            Self: PartialEq<U> + Debug,
+            U: Debug + ?Sized,
+        {
+            self.assert_eq_to_in::<Release, U>(expected)
+        }
+
+        /// [`assert!`]s that the value is within `range`, returning the value.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the value is not within `range`.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use fluent_result::expect::rls::ExpectThat;
+        ///
+        /// let percent = 42.assert_within(0..=100);
+        /// assert_eq!(percent, 42);
+        /// ```
+        #[must_use]
+        #[inline]
+        #[track_caller]
+        fn assert_within<R>(self, range: R) -> Self
+        where
+            Self: PartialOrd + Debug,
+            R: RangeBounds<Self> + Debug,
+        {
+            self.assert_within_in::<Release, R>(range)
+        }
+    }
+
+    #[sealed::sealed]
+    impl<T> ExpectThat for T {}
+}
@@ -0,0 +1,320 @@
+use core::fmt::{Arguments, Display, Formatter};
+use core::panic::Location;
+
+use derive_more::IsVariant;
+
+/// The kind of assertion that failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, IsVariant)]
+#[non_exhaustive]
+pub enum FailureKind {
+    /// A `bool` was expected to be `true`, but was `false`.
+    ExpectedTrue,
+    /// A `bool` was expected to be `false`, but was `true`.
+    ExpectedFalse,
+    /// An [`Option`] was expected to be [`None`], but was [`Some`].
+    ExpectedNone,
+    /// An iterator was expected to be empty, but had items.
+    ExpectedEmpty,
+    /// An iterator was expected to have exactly one item.
+    ExpectedSingle,
+    /// An iterator was expected to have at most a given number of items.
+    ExpectedAtMost,
+    /// An iterator was expected to have no equal items.
+    ExpectedUnique,
+    /// A value was expected to satisfy a predicate.
+    ExpectedPredicate,
+    /// A value was expected to be equal to another.
+    ExpectedEqual,
+    /// A value was expected to be within a range.
+    ExpectedInRange,
+}
+
+/// A failed assertion, as reported to the failure handler.
+///
+/// See [`set_failure_handler`] for details.
+#[derive(Debug, Clone, Copy)]
+pub struct Failure<'a> {
+    kind: FailureKind,
+    message: Arguments<'a>,
+    location: &'static Location<'static>,
+}
+
+impl<'a> Failure<'a> {
+    /// The kind of assertion that failed.
+    #[must_use]
+    pub const fn kind(&self) -> FailureKind {
+        self.kind
+    }
+
+    /// The assertion message.
+    #[must_use]
+    pub const fn message(&self) -> Arguments<'a> {
+        self.message
+    }
+
+    /// The location of the failed assertion.
+    #[must_use]
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl Display for Failure<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// An owned record of a failed assertion.
+///
+/// Created from a [`Failure`], or recorded by a [`SoftAssert`](crate::expect::SoftAssert).
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssertionFailure {
+    kind: FailureKind,
+    message: alloc::string::String,
+    location: &'static Location<'static>,
+}
+
+#[cfg(feature = "alloc")]
+impl AssertionFailure {
+    /// Creates a new [`AssertionFailure`].
+    #[must_use]
+    pub const fn new(kind: FailureKind, message: alloc::string::String, location: &'static Location<'static>) -> Self {
+        Self { kind, message, location }
+    }
+
+    /// The kind of assertion that failed.
+    #[must_use]
+    pub const fn kind(&self) -> FailureKind {
+        self.kind
+    }
+
+    /// The assertion message.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The location of the failed assertion.
+    #[must_use]
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+#[cfg(feature = "std")]
+impl AssertionFailure {
+    /// Extracts an [`AssertionFailure`] from a panic payload, for example one returned by
+    /// [`catch_unwind`](std::panic::catch_unwind).
+    ///
+    /// Assertions only panic with an [`AssertionFailure`] payload when enabled by
+    /// [`set_typed_panics`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,standalone_crate
+    /// use fluent_result::bool::rls::Expect;
+    /// use fluent_result::expect::{AssertionFailure, FailureKind, set_typed_panics};
+    ///
+    /// set_typed_panics(true);
+    ///
+    /// let payload = std::panic::catch_unwind(|| false.expect_true("should be true")).unwrap_err();
+    /// let failure = AssertionFailure::from_payload(payload.as_ref()).expect("should be a failure");
+    /// assert_eq!(failure.kind(), FailureKind::ExpectedTrue);
+    /// assert_eq!(failure.message(), "should be true");
+    /// ```
+    #[must_use]
+    pub fn from_payload(payload: &(dyn core::any::Any + Send)) -> Option<&Self> {
+        payload.downcast_ref()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&Failure<'_>> for AssertionFailure {
+    fn from(failure: &Failure<'_>) -> Self {
+        Self::new(failure.kind, alloc::string::ToString::to_string(&failure.message), failure.location)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Display for AssertionFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for AssertionFailure {}
+
+#[cfg(feature = "std")]
+type SharedHandler = std::sync::Arc<dyn Fn(&Failure<'_>) -> bool + Send + Sync>;
+
+#[cfg(feature = "std")]
+static HANDLER: std::sync::RwLock<Option<SharedHandler>> = std::sync::RwLock::new(None);
+
+#[cfg(all(not(feature = "std"), feature = "spin"))]
+static HANDLER: spin::RwLock<Option<FailureHandler>> = spin::RwLock::new(None);
+
+/// A function pointer that handles a failed assertion, see [`set_failure_handler`].
+#[cfg(all(not(feature = "std"), feature = "spin"))]
+pub type FailureHandler = fn(&Failure<'_>) -> bool;
+
+/// Sets the global handler called when any `Expect` or `ExpectNone` assertion fails.
+///
+/// The handler receives the [`Failure`], and returns whether the assertion should panic. This
+/// allows failures to be reported, for example to logs or telemetry, and then ignored. The
+/// handler replaces any previously set handler. Without a handler, failed assertions always
+/// panic.
+///
+/// Debug-only assertions are still compiled out in release builds, and will not call the handler.
+///
+/// The handler should not itself set or reset the failure handler.
+///
+/// # Example
+///
+/// ```rust,standalone_crate
+/// use fluent_result::bool::rls::Expect;
+/// use fluent_result::expect::{reset_failure_handler, set_failure_handler};
+///
+/// // report failures without panicking
+/// set_failure_handler(|failure| {
+///     eprintln!("assertion failed at {}: {}", failure.location(), failure);
+///     false
+/// });
+/// false.expect_true("should be true");
+///
+/// reset_failure_handler();
+/// ```
+#[cfg(feature = "std")]
+pub fn set_failure_handler<F>(handler: F)
+where
+    F: Fn(&Failure<'_>) -> bool + Send + Sync + 'static,
+{
+    *HANDLER.write().unwrap_or_else(std::sync::PoisonError::into_inner) = Some(std::sync::Arc::new(handler));
+}
+
+/// Sets the global handler called when any `Expect` or `ExpectNone` assertion fails.
+///
+/// The handler receives the [`Failure`], and returns whether the assertion should panic. This
+/// allows failures to be reported, for example to logs or telemetry, and then ignored. The
+/// handler replaces any previously set handler. Without a handler, failed assertions always
+/// panic.
+///
+/// Debug-only assertions are still compiled out in release builds, and will not call the handler.
+///
+/// Without the `std` feature the handler must be a function pointer, and the `spin` feature is
+/// required.
+#[cfg(all(not(feature = "std"), feature = "spin"))]
+pub fn set_failure_handler(handler: FailureHandler) {
+    *HANDLER.write() = Some(handler);
+}
+
+#[cfg(feature = "std")]
+static TYPED_PANICS: core::sync::atomic::AtomicBool = core::sync::atomic::AtomicBool::new(false);
+
+/// Sets whether failed assertions panic with a typed [`AssertionFailure`] payload.
+///
+/// By default, failed assertions panic with a formatted message. When enabled, they instead panic
+/// via [`panic_any`](std::panic::panic_any) with an [`AssertionFailure`] payload, which can be
+/// recovered from a caught panic with [`AssertionFailure::from_payload`]. This allows test
+/// harnesses and supervisors to inspect failures without matching on text.
+///
+/// Enabling typed panics installs a panic hook which reports [`AssertionFailure`] payloads in the
+/// same format as the default hook, and forwards all other panics to the previous hook.
+///
+/// Note that `#[should_panic(expected = "...")]` tests cannot match typed payloads.
+///
+/// # Example
+///
+/// ```rust,standalone_crate
+/// use fluent_result::bool::rls::Expect;
+/// use fluent_result::expect::{AssertionFailure, FailureKind, set_typed_panics};
+///
+/// set_typed_panics(true);
+///
+/// let payload = std::panic::catch_unwind(|| false.expect_true("should be true")).unwrap_err();
+/// let failure = AssertionFailure::from_payload(payload.as_ref()).unwrap();
+/// assert_eq!(FailureKind::ExpectedTrue, failure.kind());
+/// ```
+#[cfg(feature = "std")]
+pub fn set_typed_panics(enabled: bool) {
+    if enabled {
+        install_panic_hook();
+    }
+    TYPED_PANICS.store(enabled, core::sync::atomic::Ordering::Relaxed);
+}
+
+/// Installs a panic hook that reports [`AssertionFailure`] payloads readably, once.
+#[cfg(feature = "std")]
+fn install_panic_hook() {
+    static INSTALL: std::sync::Once = std::sync::Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(alloc::boxed::Box::new(move |info| {
+            match AssertionFailure::from_payload(info.payload()) {
+                Some(failure) => {
+                    let thread = std::thread::current();
+                    let name = thread.name().unwrap_or("<unnamed>");
+                    std::eprintln!("thread '{name}' panicked at {}:\n{}", failure.location, failure.message);
+                }
+                None => previous(info),
+            }
+        }));
+    });
+}
+
+/// Removes the global failure handler, restoring the default behavior of always panicking.
+///
+/// Without the `std` feature, the `spin` feature is required.
+#[cfg(any(feature = "std", feature = "spin"))]
+pub fn reset_failure_handler() {
+    #[cfg(feature = "std")]
+    let mut handler = HANDLER.write().unwrap_or_else(std::sync::PoisonError::into_inner);
+    #[cfg(all(not(feature = "std"), feature = "spin"))]
+    let mut handler = HANDLER.write();
+
+    *handler = None;
+}
+
+/// Runs the global failure handler, returning whether to panic.
+#[cfg(any(feature = "std", feature = "spin"))]
+fn handle(failure: &Failure<'_>) -> bool {
+    #[cfg(feature = "std")]
+    let handler = HANDLER.read().unwrap_or_else(std::sync::PoisonError::into_inner).clone();
+    #[cfg(all(not(feature = "std"), feature = "spin"))]
+    let handler = *HANDLER.read();
+
+    handler.is_none_or(|handler| handler(failure))
+}
+
+/// Without a failure handler, failed assertions always panic.
+///
+/// There is no `core` only handler, since `core` can only store integers and raw pointers in a
+/// `static`, and reading a function pointer back from a raw pointer requires `unsafe` code.
+#[cfg(not(any(feature = "std", feature = "spin")))]
+const fn handle(_: &Failure<'_>) -> bool {
+    true
+}
+
+/// Reports a failed assertion to the global failure handler, panicking if it requests so.
+///
+/// # Panics
+///
+/// Panics with `message`, or an [`AssertionFailure`] if [typed panics](set_typed_panics) are
+/// enabled, unless the failure handler returns `false`.
+#[track_caller]
+pub fn fail(kind: FailureKind, message: Arguments<'_>) {
+    let failure = Failure { kind, message, location: Location::caller() };
+    if !handle(&failure) {
+        return;
+    }
+
+    #[cfg(feature = "std")]
+    if TYPED_PANICS.load(core::sync::atomic::Ordering::Relaxed) {
+        std::panic::panic_any(AssertionFailure::from(&failure));
+    }
+
+    panic!("{failure}");
+}
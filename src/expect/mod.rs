@@ -1,5 +1,43 @@
+mod expect_display;
+mod expect_items;
 mod expect_none;
+mod expect_that;
+mod failure;
+#[cfg(feature = "alloc")]
+mod soft_assert;
 mod unwrap_never;
 
-pub use expect_none::{dbg, rls};
+/// Assertion modes, determining whether assertions are checked.
+pub mod mode;
+
+/// Debug-only [`debug_assert!`]s for [`Option`] values, iterators and any value.
+pub mod dbg {
+    pub use super::expect_items::dbg::ExpectItems;
+    pub use super::expect_none::dbg::ExpectNone;
+    pub use super::expect_that::dbg::ExpectThat;
+}
+
+/// Release-mode [`assert!`]s for [`Option`] values, iterators and any value.
+pub mod rls {
+    pub use super::expect_items::rls::ExpectItems;
+    pub use super::expect_none::rls::ExpectNone;
+    pub use super::expect_that::rls::ExpectThat;
+}
+
+pub use expect_display::{ErrorReport, ExpectDisplay};
+pub use expect_items::{CountError, DuplicateError, ExpectItemsIn, TryItems};
+pub use expect_none::ExpectNoneIn;
+pub use expect_that::ExpectThatIn;
+#[cfg(feature = "alloc")]
+pub use failure::AssertionFailure;
+#[cfg(all(not(feature = "std"), feature = "spin"))]
+pub use failure::FailureHandler;
+pub(crate) use failure::fail;
+#[cfg(feature = "std")]
+pub use failure::set_typed_panics;
+pub use failure::{Failure, FailureKind};
+#[cfg(any(feature = "std", feature = "spin"))]
+pub use failure::{reset_failure_handler, set_failure_handler};
+#[cfg(feature = "alloc")]
+pub use soft_assert::{AssertionFailures, SoftAssert};
 pub use unwrap_never::UnwrapNever;
@@ -0,0 +1,45 @@
+/// An assertion mode, determining whether assertions are checked.
+///
+/// The `*_in` assertion methods, such as [`ExpectIn::assert_true_in`](crate::bool::ExpectIn::assert_true_in)
+/// and [`ExpectNoneIn::assert_none_in`](crate::expect::ExpectNoneIn::assert_none_in), are generic
+/// over a [`Mode`]. [`Debug`](struct@Debug) and [`Release`] are provided, mirroring [`debug_assert!`] and
+/// [`assert!`] respectively, but custom modes can be defined as well.
+///
+/// Failed assertions in an enabled mode are reported to the
+/// [failure handler](crate::expect::set_failure_handler).
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::bool::ExpectIn;
+/// use fluent_result::expect::mode::Mode;
+///
+/// /// Only checks assertions when the `strict` feature is enabled.
+/// struct Strict;
+///
+/// impl Mode for Strict {
+///     const ENABLED: bool = cfg!(feature = "strict");
+/// }
+///
+/// false.assert_true_in::<Strict>();
+/// ```
+pub trait Mode {
+    /// Whether assertions in this mode are checked.
+    const ENABLED: bool;
+}
+
+/// Checks assertions only in debug builds (`cfg(debug_assertions)`), like [`debug_assert!`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Debug;
+
+impl Mode for Debug {
+    const ENABLED: bool = cfg!(debug_assertions);
+}
+
+/// Checks assertions in all build modes, like [`assert!`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Release;
+
+impl Mode for Release {
+    const ENABLED: bool = true;
+}
@@ -0,0 +1,134 @@
+use core::panic::Location;
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::error::MultiError;
+use crate::expect::{AssertionFailure, FailureKind};
+
+/// A scope that records failed `bool` and [`None`] assertions instead of panicking on the first.
+///
+/// This offers the same vocabulary as [`Expect`](crate::bool::rls::Expect) and
+/// [`ExpectNone`](crate::expect::rls::ExpectNone), but every failure is recorded along with its
+/// location. Once all checks have run, [`SoftAssert::finish`] returns every failure at once.
+///
+/// Assertions are always checked, regardless of build mode, and failures are not reported to the
+/// [failure handler](crate::expect::set_failure_handler).
+///
+/// # Panics
+///
+/// If dropped without calling [`SoftAssert::finish`], panics with a report of all recorded
+/// failures, if any. Without the `std` feature this panic also occurs while already panicking,
+/// which aborts.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::expect::SoftAssert;
+///
+/// let mut soft = SoftAssert::new();
+/// soft.assert_true(1 + 1 == 2)
+///     .expect_false(1 + 1 == 3, "math is broken")
+///     .expect_none(None::<u32>, "should be empty");
+/// assert!(soft.finish().is_ok());
+///
+/// let mut soft = SoftAssert::new();
+/// soft.expect_true(false, "first").expect_none(Some(42), "second");
+/// let failures = soft.finish().expect_err("should fail");
+/// assert_eq!(failures.len(), 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct SoftAssert {
+    failures: Vec<AssertionFailure>,
+}
+
+impl SoftAssert {
+    /// Creates a new, empty [`SoftAssert`] scope.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { failures: Vec::new() }
+    }
+
+    /// Asserts that `value` is `true`.
+    #[track_caller]
+    pub fn assert_true(&mut self, value: bool) -> &mut Self {
+        self.check(value, FailureKind::ExpectedTrue, "assertion failed: expected `true` but was `false`")
+    }
+
+    /// Asserts that `value` is `true` with `msg` as the assertion message.
+    #[track_caller]
+    pub fn expect_true(&mut self, value: bool, msg: &str) -> &mut Self {
+        self.check(value, FailureKind::ExpectedTrue, msg)
+    }
+
+    /// Asserts that `value` is `false`.
+    #[track_caller]
+    pub fn assert_false(&mut self, value: bool) -> &mut Self {
+        self.check(!value, FailureKind::ExpectedFalse, "assertion failed: expected `false` but was `true`")
+    }
+
+    /// Asserts that `value` is `false` with `msg` as the assertion message.
+    #[track_caller]
+    pub fn expect_false(&mut self, value: bool, msg: &str) -> &mut Self {
+        self.check(!value, FailureKind::ExpectedFalse, msg)
+    }
+
+    /// Asserts that `option` is [`None`].
+    #[track_caller]
+    #[allow(clippy::needless_pass_by_value, reason = "mirrors `ExpectNone`, which consumes the option")]
+    pub fn assert_none<T>(&mut self, option: Option<T>) -> &mut Self {
+        self.check(option.is_none(), FailureKind::ExpectedNone, "called `Option::assert_none()` on a `Some` value")
+    }
+
+    /// Asserts that `option` is [`None`] with `msg` as the assertion message.
+    #[track_caller]
+    #[allow(clippy::needless_pass_by_value, reason = "mirrors `ExpectNone`, which consumes the option")]
+    pub fn expect_none<T>(&mut self, option: Option<T>, msg: &str) -> &mut Self {
+        self.check(option.is_none(), FailureKind::ExpectedNone, msg)
+    }
+
+    /// The failures recorded so far.
+    #[must_use]
+    pub fn failures(&self) -> &[AssertionFailure] {
+        &self.failures
+    }
+
+    /// Ends the scope, returning all recorded failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AssertionFailures`] if any assertion failed.
+    pub fn finish(mut self) -> Result<(), AssertionFailures> {
+        match core::mem::take(&mut self.failures) {
+            failures if failures.is_empty() => Ok(()),
+            failures => Err(MultiError::new(failures)),
+        }
+    }
+
+    #[track_caller]
+    fn check(&mut self, passed: bool, kind: FailureKind, msg: &str) -> &mut Self {
+        if !passed {
+            self.failures.push(AssertionFailure::new(kind, msg.to_string(), Location::caller()));
+        }
+        self
+    }
+}
+
+impl Drop for SoftAssert {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        if std::thread::panicking() {
+            return;
+        }
+
+        if !self.failures.is_empty() {
+            let failures = MultiError::new(core::mem::take(&mut self.failures));
+            panic!("{failures}");
+        }
+    }
+}
+
+/// All the failures recorded by a [`SoftAssert`] scope.
+///
+/// Displays as a numbered list of the failures and their locations.
+pub type AssertionFailures = MultiError<AssertionFailure>;
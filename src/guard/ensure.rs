@@ -0,0 +1,138 @@
+use core::ops::{Bound, RangeBounds};
+
+use crate::guard::{Mismatch, OutOfRange};
+
+/// An extension trait for comparable values, that provides guards returning structured errors.
+///
+/// Unlike `(value > max).then_err(err)`, the errors keep the actual value and the expected value
+/// or bounds, so they can be reported without extra context.
+#[sealed::sealed]
+pub trait Ensure: Sized {
+    /// Returns [`Ok`] with the value if it is equal to `expected`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Mismatch`] with a clone of `expected` if the value is not equal to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::guard::{Ensure, Mismatch};
+    ///
+    /// assert_eq!(2.ensure_eq(&2), Ok(2));
+    /// assert_eq!(1.ensure_eq(&2), Err(Mismatch { expected: 2, actual: 1 }));
+    /// assert_eq!(1.ensure_eq(&2).unwrap_err().to_string(), "expected `2` but was `1`");
+    /// ```
+    fn ensure_eq(self, expected: &Self) -> Result<Self, Mismatch<Self>>
+    where
+        Self: PartialEq + Clone;
+
+    /// Returns [`Ok`] with the value if it is within `range`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutOfRange`] with clones of the bounds of `range` if the value is not within
+    /// it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::guard::Ensure;
+    ///
+    /// assert_eq!(42.ensure_in(0..=100), Ok(42));
+    ///
+    /// let err = 142.ensure_in(0..=100).unwrap_err();
+    /// assert_eq!(err.actual, 142);
+    /// assert_eq!(err.to_string(), "expected a value at least `0` and at most `100` but was `142`");
+    /// ```
+    fn ensure_in<R>(self, range: R) -> Result<Self, OutOfRange<Self>>
+    where
+        Self: PartialOrd + Clone,
+        R: RangeBounds<Self>;
+
+    /// Returns [`Ok`] with the value if it is at most `max`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutOfRange`] bounded by `max` if the value is greater than `max`, or is not
+    /// comparable to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::guard::Ensure;
+    ///
+    /// assert_eq!(8.ensure_at_most(10), Ok(8));
+    /// assert_eq!(12.ensure_at_most(10).unwrap_err().to_string(), "expected a value at most `10` but was `12`");
+    /// ```
+    fn ensure_at_most(self, max: Self) -> Result<Self, OutOfRange<Self>>
+    where
+        Self: PartialOrd;
+
+    /// Returns [`Ok`] with the value if it is at least `min`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutOfRange`] bounded by `min` if the value is less than `min`, or is not
+    /// comparable to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::guard::Ensure;
+    ///
+    /// assert_eq!(21.ensure_at_least(18), Ok(21));
+    /// assert_eq!(16.ensure_at_least(18).unwrap_err().to_string(), "expected a value at least `18` but was `16`");
+    /// ```
+    fn ensure_at_least(self, min: Self) -> Result<Self, OutOfRange<Self>>
+    where
+        Self: PartialOrd;
+}
+
+#[sealed::sealed]
+impl<T> Ensure for T {
+    #[inline]
+    fn ensure_eq(self, expected: &Self) -> Result<Self, Mismatch<Self>>
+    where
+        Self: PartialEq + Clone,
+    {
+        if self == *expected { Ok(self) } else { Err(Mismatch { expected: expected.clone(), actual: self }) }
+    }
+
+    #[inline]
+    fn ensure_in<R>(self, range: R) -> Result<Self, OutOfRange<Self>>
+    where
+        Self: PartialOrd + Clone,
+        R: RangeBounds<Self>,
+    {
+        if range.contains(&self) {
+            Ok(self)
+        } else {
+            Err(OutOfRange { start: range.start_bound().cloned(), end: range.end_bound().cloned(), actual: self })
+        }
+    }
+
+    #[inline]
+    fn ensure_at_most(self, max: Self) -> Result<Self, OutOfRange<Self>>
+    where
+        Self: PartialOrd,
+    {
+        if self <= max {
+            Ok(self)
+        } else {
+            Err(OutOfRange { start: Bound::Unbounded, end: Bound::Included(max), actual: self })
+        }
+    }
+
+    #[inline]
+    fn ensure_at_least(self, min: Self) -> Result<Self, OutOfRange<Self>>
+    where
+        Self: PartialOrd,
+    {
+        if self >= min {
+            Ok(self)
+        } else {
+            Err(OutOfRange { start: Bound::Included(min), end: Bound::Unbounded, actual: self })
+        }
+    }
+}
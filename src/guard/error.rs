@@ -0,0 +1,56 @@
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+use core::ops::Bound;
+
+#[cfg(doc)]
+use crate::guard::Ensure;
+
+/// An error created by [`Ensure::ensure_eq`] when a value is not equal to the expected value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mismatch<T> {
+    /// The expected value.
+    pub expected: T,
+    /// The actual value.
+    pub actual: T,
+}
+
+impl<T: Debug> Display for Mismatch<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected `{:?}` but was `{:?}`", self.expected, self.actual)
+    }
+}
+
+impl<T: Debug> Error for Mismatch<T> {}
+
+/// An error created by the range guards of [`Ensure`] when a value is outside its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutOfRange<T> {
+    /// The lower bound.
+    pub start: Bound<T>,
+    /// The upper bound.
+    pub end: Bound<T>,
+    /// The actual value.
+    pub actual: T,
+}
+
+impl<T: Debug> Display for OutOfRange<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected a value")?;
+        match &self.start {
+            Bound::Included(start) => write!(f, " at least `{start:?}`")?,
+            Bound::Excluded(start) => write!(f, " greater than `{start:?}`")?,
+            Bound::Unbounded => {}
+        }
+        if !matches!(self.start, Bound::Unbounded) && !matches!(self.end, Bound::Unbounded) {
+            write!(f, " and")?;
+        }
+        match &self.end {
+            Bound::Included(end) => write!(f, " at most `{end:?}`")?,
+            Bound::Excluded(end) => write!(f, " less than `{end:?}`")?,
+            Bound::Unbounded => {}
+        }
+        write!(f, " but was `{:?}`", self.actual)
+    }
+}
+
+impl<T: Debug> Error for OutOfRange<T> {}
@@ -0,0 +1,5 @@
+mod ensure;
+mod error;
+
+pub use ensure::Ensure;
+pub use error::{Mismatch, OutOfRange};
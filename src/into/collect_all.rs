@@ -0,0 +1,98 @@
+use alloc::vec::Vec;
+
+use crate::sink::SinkResultIter;
+
+/// An extension trait for iterators of [`Result`]s, that collects every error instead of stopping
+/// at the first.
+///
+/// `collect::<Result<C, E>>()` only keeps the first error. The errors returned by these methods
+/// can be converted into a [`MultiError`](crate::error::MultiError) with `?`.
+#[sealed::sealed]
+pub trait CollectAll<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects the [`Ok`] values into `C` if there are no errors, and returns every [`Err`]
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns every error, in order, if there are any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::into::CollectAll;
+    ///
+    /// let results = [Ok(1), Err("first"), Ok(2), Err("second")];
+    /// assert_eq!(results.into_iter().collect_all::<Vec<_>>(), Err(vec!["first", "second"]));
+    ///
+    /// let results = [Ok::<_, &str>(1), Ok(2)];
+    /// assert_eq!(results.into_iter().collect_all::<Vec<_>>(), Ok(vec![1, 2]));
+    /// ```
+    fn collect_all<C: FromIterator<T>>(self) -> Result<C, Vec<E>>;
+
+    /// Collects the [`Ok`] values and the [`Err`] values separately.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::into::CollectAll;
+    ///
+    /// let results = [Ok(1), Err("first"), Ok(2)];
+    /// assert_eq!(results.into_iter().partition_results(), (vec![1, 2], vec!["first"]));
+    /// ```
+    fn partition_results(self) -> (Vec<T>, Vec<E>);
+
+    /// Collects the [`Ok`] values into `C` if there are no errors, and returns at most `n` errors
+    /// otherwise.
+    ///
+    /// The iterator is not advanced once `n` errors have been collected. At least one error is
+    /// always returned, so a limit of `0` behaves like a limit of `1`, returning at the first
+    /// error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first `n` errors, or the first error if `n` is `0`, in order, if there are any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::into::CollectAll;
+    ///
+    /// let results = [Err("first"), Ok(1), Err("second"), Err("third")];
+    /// assert_eq!(results.into_iter().collect_errs_limit::<Vec<_>>(2), Err(vec!["first", "second"]));
+    /// ```
+    fn collect_errs_limit<C: FromIterator<T>>(self, n: usize) -> Result<C, Vec<E>>;
+}
+
+#[sealed::sealed]
+impl<T, E, I: Iterator<Item = Result<T, E>>> CollectAll<T, E> for I {
+    #[inline]
+    fn collect_all<C: FromIterator<T>>(self) -> Result<C, Vec<E>> {
+        let mut errors = Vec::new();
+        let collection = self.sink_errs_into(&mut errors).collect();
+        if errors.is_empty() { Ok(collection) } else { Err(errors) }
+    }
+
+    #[inline]
+    fn partition_results(self) -> (Vec<T>, Vec<E>) {
+        let (mut values, mut errors) = (Vec::new(), Vec::new());
+        self.drain_into(&mut values, &mut errors);
+        (values, errors)
+    }
+
+    #[inline]
+    fn collect_errs_limit<C: FromIterator<T>>(self, n: usize) -> Result<C, Vec<E>> {
+        let n = n.max(1);
+        let mut errors = Vec::new();
+        let collection = self
+            .map_while(|result| match result {
+                Ok(value) => Some(Some(value)),
+                Err(err) => {
+                    errors.push(err);
+                    (errors.len() < n).then_some(None)
+                }
+            })
+            .flatten()
+            .collect();
+        if errors.is_empty() { Ok(collection) } else { Err(errors) }
+    }
+}
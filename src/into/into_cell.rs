@@ -0,0 +1,43 @@
+use core::cell::{Cell, RefCell};
+
+/// Provides postfix conversion of any value into a [`Cell`] or [`RefCell`].
+pub trait IntoCell {
+    /// Moves a value into a [`Cell`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoCell;
+    ///
+    /// let cell = 42.into_cell();
+    /// cell.set(7);
+    /// assert_eq!(cell.get(), 7);
+    /// ```
+    #[inline]
+    fn into_cell(self) -> Cell<Self>
+    where
+        Self: Sized,
+    {
+        Cell::new(self)
+    }
+
+    /// Moves a value into a [`RefCell`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoCell;
+    ///
+    /// let cell = vec![1, 2].into_ref_cell();
+    /// cell.borrow_mut().push(3);
+    /// assert_eq!(*cell.borrow(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn into_ref_cell(self) -> RefCell<Self>
+    where
+        Self: Sized,
+    {
+        RefCell::new(self)
+    }
+}
+
+/// Implements `IntoCell` for all types.
+impl<T> IntoCell for T {}
@@ -0,0 +1,116 @@
+use core::ops::ControlFlow;
+
+/// Provides postfix conversion of any value into a [`ControlFlow`].
+pub trait IntoControlFlow {
+    /// Moves a value into a [`Break`](ControlFlow::Break) variant of [`ControlFlow`].
+    ///
+    /// When used in terminal position, `C` can be inferred.
+    /// When used in other positions, `C` can be specified.
+    ///
+    /// # Type Parameters
+    /// - `C`: The continue type to use in the [`ControlFlow`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::ops::ControlFlow;
+    /// use fluent_result::into::IntoControlFlow;
+    ///
+    /// let found = [1, 2, 3].iter().try_for_each(|n| if *n == 2 { n.into_break() } else { ().into_continue() });
+    /// assert_eq!(found, ControlFlow::Break(&2));
+    /// ```
+    #[inline]
+    fn into_break<C>(self) -> ControlFlow<Self, C>
+    where
+        Self: Sized,
+    {
+        ControlFlow::Break(self)
+    }
+
+    /// Moves a value into a [`Continue`](ControlFlow::Continue) variant of [`ControlFlow`].
+    ///
+    /// When used in terminal position, `B` can be inferred.
+    /// When used in other positions, `B` can be specified.
+    ///
+    /// # Type Parameters
+    /// - `B`: The break type to use in the [`ControlFlow`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::ops::ControlFlow;
+    /// use fluent_result::into::IntoControlFlow;
+    ///
+    /// let flow = 42.into_continue::<&str>();
+    /// assert_eq!(flow, ControlFlow::Continue(42));
+    /// ```
+    #[inline]
+    fn into_continue<B>(self) -> ControlFlow<B, Self>
+    where
+        Self: Sized,
+    {
+        ControlFlow::Continue(self)
+    }
+}
+
+/// Implements `IntoControlFlow` for all types.
+impl<T> IntoControlFlow for T {}
+
+/// Provides conversion of a [`Result`] into a [`ControlFlow`], breaking on [`Err`].
+#[sealed::sealed]
+pub trait ResultIntoControlFlow<T, E> {
+    /// Converts [`Ok(value)`](Ok) into [`Continue(value)`](ControlFlow::Continue) and
+    /// [`Err(err)`](Err) into [`Break(err)`](ControlFlow::Break).
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::ops::ControlFlow;
+    /// use fluent_result::into::ResultIntoControlFlow;
+    ///
+    /// let flow = ["1", "x", "3"].iter().try_for_each(|s| s.parse::<u32>().map(drop).into_control_flow());
+    /// assert!(flow.is_break());
+    /// ```
+    fn into_control_flow(self) -> ControlFlow<E, T>;
+}
+
+#[sealed::sealed]
+impl<T, E> ResultIntoControlFlow<T, E> for Result<T, E> {
+    #[inline]
+    fn into_control_flow(self) -> ControlFlow<E, T> {
+        match self {
+            Ok(value) => ControlFlow::Continue(value),
+            Err(err) => ControlFlow::Break(err),
+        }
+    }
+}
+
+/// Provides conversion of a [`ControlFlow`] into a [`Result`], with [`Break`](ControlFlow::Break) as
+/// [`Err`].
+#[sealed::sealed]
+pub trait ControlFlowIntoResult<B, C> {
+    /// Converts [`Continue(value)`](ControlFlow::Continue) into [`Ok(value)`](Ok) and
+    /// [`Break(value)`](ControlFlow::Break) into [`Err(value)`](Err).
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Break`](ControlFlow::Break) value as [`Err`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::ops::ControlFlow;
+    /// use fluent_result::into::ControlFlowIntoResult;
+    ///
+    /// let flow = [1, 2, 3].iter().try_for_each(|n| if *n > 2 { ControlFlow::Break(*n) } else { ControlFlow::Continue(()) });
+    /// assert_eq!(flow.into_result(), Err(3));
+    /// ```
+    fn into_result(self) -> Result<C, B>;
+}
+
+#[sealed::sealed]
+impl<B, C> ControlFlowIntoResult<B, C> for ControlFlow<B, C> {
+    #[inline]
+    fn into_result(self) -> Result<C, B> {
+        match self {
+            Self::Continue(value) => Ok(value),
+            Self::Break(value) => Err(value),
+        }
+    }
+}
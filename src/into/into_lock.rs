@@ -0,0 +1,82 @@
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Provides postfix conversion of any value into a lock.
+pub trait IntoLock {
+    /// Moves a value into a [`Mutex`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoLock;
+    ///
+    /// let lock = 42.into_mutex();
+    /// assert_eq!(*lock.lock().unwrap(), 42);
+    /// ```
+    #[inline]
+    fn into_mutex(self) -> Mutex<Self>
+    where
+        Self: Sized,
+    {
+        Mutex::new(self)
+    }
+
+    /// Moves a value into an [`RwLock`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoLock;
+    ///
+    /// let lock = 42.into_rw_lock();
+    /// assert_eq!(*lock.read().unwrap(), 42);
+    /// ```
+    #[inline]
+    fn into_rw_lock(self) -> RwLock<Self>
+    where
+        Self: Sized,
+    {
+        RwLock::new(self)
+    }
+
+    /// Moves a value into an [`Arc`] of a [`Mutex`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::thread;
+    /// use fluent_result::into::IntoLock;
+    ///
+    /// let counter = 0.into_arc_mutex();
+    /// let handle = thread::spawn({
+    ///     let counter = counter.clone();
+    ///     move || *counter.lock().unwrap() += 1
+    /// });
+    /// handle.join().unwrap();
+    /// assert_eq!(*counter.lock().unwrap(), 1);
+    /// ```
+    #[inline]
+    fn into_arc_mutex(self) -> Arc<Mutex<Self>>
+    where
+        Self: Sized,
+    {
+        Arc::new(Mutex::new(self))
+    }
+
+    /// Moves a value into an [`Arc`] of an [`RwLock`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoLock;
+    ///
+    /// let config = vec!["a"].into_arc_rw_lock();
+    /// config.clone().write().unwrap().push("b");
+    /// assert_eq!(*config.read().unwrap(), ["a", "b"]);
+    /// ```
+    #[inline]
+    fn into_arc_rw_lock(self) -> Arc<RwLock<Self>>
+    where
+        Self: Sized,
+    {
+        Arc::new(RwLock::new(self))
+    }
+}
+
+/// Implements `IntoLock` for all types.
+impl<T> IntoLock for T {}
@@ -17,6 +17,42 @@ pub trait IntoOption {
         Some(self)
     }
 
+    /// Moves a value into a [Some] variant of [Option] if `predicate` returns `true`, and returns
+    /// [None] otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoOption;
+    ///
+    /// assert_eq!(42.into_some_if(|n| n % 2 == 0), Some(42));
+    /// assert_eq!(41.into_some_if(|n| n % 2 == 0), None);
+    /// ```
+    #[inline]
+    fn into_some_if(self, predicate: impl FnOnce(&Self) -> bool) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if predicate(&self) { Some(self) } else { None }
+    }
+
+    /// Moves a value into a [Some] variant of [Option] if `predicate` returns `false`, and returns
+    /// [None] otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoOption;
+    ///
+    /// assert_eq!("alice".into_some_unless(|name| name.is_empty()), Some("alice"));
+    /// assert_eq!("".into_some_unless(|name| name.is_empty()), None);
+    /// ```
+    #[inline]
+    fn into_some_unless(self, predicate: impl FnOnce(&Self) -> bool) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if predicate(&self) { None } else { Some(self) }
+    }
+
     /// Turns a value into [None].
     ///
     /// When used in terminal position, `TOut` can be inferred.
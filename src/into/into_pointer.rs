@@ -0,0 +1,97 @@
+use core::cell::RefCell;
+use core::pin::Pin;
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+
+/// Provides postfix conversion of any value into a smart pointer.
+pub trait IntoPointer {
+    /// Moves a value into a [`Box`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoPointer;
+    ///
+    /// let boxed = 42.into_box();
+    /// assert_eq!(*boxed, 42);
+    /// ```
+    #[inline]
+    fn into_box(self) -> Box<Self>
+    where
+        Self: Sized,
+    {
+        Box::new(self)
+    }
+
+    /// Moves a value into a pinned [`Box`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoPointer;
+    ///
+    /// let pinned = async { 42 }.into_pin_box();
+    /// ```
+    #[inline]
+    fn into_pin_box(self) -> Pin<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Box::pin(self)
+    }
+
+    /// Moves a value into an [`Rc`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoPointer;
+    ///
+    /// let shared = 42.into_rc();
+    /// assert_eq!(*shared.clone(), 42);
+    /// ```
+    #[inline]
+    fn into_rc(self) -> Rc<Self>
+    where
+        Self: Sized,
+    {
+        Rc::new(self)
+    }
+
+    /// Moves a value into an [`Arc`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoPointer;
+    ///
+    /// let shared = 42.into_arc();
+    /// assert_eq!(*shared.clone(), 42);
+    /// ```
+    #[inline]
+    fn into_arc(self) -> Arc<Self>
+    where
+        Self: Sized,
+    {
+        Arc::new(self)
+    }
+
+    /// Moves a value into an [`Rc`] of a [`RefCell`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoPointer;
+    ///
+    /// let shared = vec![1, 2].into_rc_ref_cell();
+    /// shared.clone().borrow_mut().push(3);
+    /// assert_eq!(*shared.borrow(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn into_rc_ref_cell(self) -> Rc<RefCell<Self>>
+    where
+        Self: Sized,
+    {
+        Rc::new(RefCell::new(self))
+    }
+}
+
+/// Implements `IntoPointer` for all types.
+impl<T> IntoPointer for T {}
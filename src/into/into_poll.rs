@@ -0,0 +1,70 @@
+use core::task::Poll;
+
+/// Provides postfix conversion of any value into a [`Poll`].
+pub trait IntoPoll {
+    /// Moves a value into a [`Ready`](Poll::Ready) variant of [`Poll`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::task::Poll;
+    /// use fluent_result::into::IntoPoll;
+    ///
+    /// assert_eq!(42.into_ready(), Poll::Ready(42));
+    /// ```
+    #[inline]
+    fn into_ready(self) -> Poll<Self>
+    where
+        Self: Sized,
+    {
+        Poll::Ready(self)
+    }
+
+    /// Moves a value into a [`Ready`](Poll::Ready) variant of [`Poll`] containing an [`Ok`].
+    ///
+    /// When used in terminal position, `E` can be inferred.
+    /// When used in other positions, `E` can be specified.
+    ///
+    /// # Type Parameters
+    /// - `E`: The error type to use in the [`Result`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::task::Poll;
+    /// use fluent_result::into::IntoPoll;
+    ///
+    /// assert_eq!(42.into_ready_ok::<&str>(), Poll::Ready(Ok(42)));
+    /// ```
+    #[inline]
+    fn into_ready_ok<E>(self) -> Poll<Result<Self, E>>
+    where
+        Self: Sized,
+    {
+        Poll::Ready(Ok(self))
+    }
+
+    /// Moves an error value into a [`Ready`](Poll::Ready) variant of [`Poll`] containing an [`Err`].
+    ///
+    /// When used in terminal position, `T` can be inferred.
+    /// When used in other positions, `T` can be specified.
+    ///
+    /// # Type Parameters
+    /// - `T`: The success type to use in the [`Result`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::task::Poll;
+    /// use fluent_result::into::IntoPoll;
+    ///
+    /// assert_eq!("error".into_ready_err::<u32>(), Poll::Ready(Err("error")));
+    /// ```
+    #[inline]
+    fn into_ready_err<T>(self) -> Poll<Result<T, Self>>
+    where
+        Self: Sized,
+    {
+        Poll::Ready(Err(self))
+    }
+}
+
+/// Implements `IntoPoll` for all types.
+impl<T> IntoPoll for T {}
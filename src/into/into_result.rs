@@ -28,6 +28,60 @@ pub trait IntoResult {
         Ok(self)
     }
 
+    /// Moves a value into an [Ok] variant of a [Result] if `predicate` returns `true`, and
+    /// returns `err` as an [Err] otherwise.
+    ///
+    /// Note `err` is eagerly evaluated, for a lazily evaluated version see
+    /// [`IntoResult::into_ok_if_else`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err(err)`](Err) if `predicate` returns `false`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoResult;
+    ///
+    /// assert_eq!(42.into_ok_if(|n| n % 2 == 0, "odd"), Ok(42));
+    /// assert_eq!(41.into_ok_if(|n| n % 2 == 0, "odd"), Err("odd"));
+    /// ```
+    #[inline]
+    fn into_ok_if<E>(self, predicate: impl FnOnce(&Self) -> bool, err: E) -> Result<Self, E>
+    where
+        Self: Sized,
+    {
+        if predicate(&self) { Ok(self) } else { Err(err) }
+    }
+
+    /// Moves a value into an [Ok] variant of a [Result] if `predicate` returns `true`, and
+    /// otherwise passes the value to `factory` to create an [Err].
+    ///
+    /// Since `factory` receives the value back, it can be moved into the error without cloning.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value created by `factory` as [Err] if `predicate` returns `false`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoResult;
+    ///
+    /// let name = String::from("");
+    /// let result = name.into_ok_if_else(|name| !name.is_empty(), |name| format!("invalid name `{name}`"));
+    /// assert_eq!(result, Err("invalid name ``".to_string()));
+    /// ```
+    #[inline]
+    fn into_ok_if_else<E>(
+        self,
+        predicate: impl FnOnce(&Self) -> bool,
+        factory: impl FnOnce(Self) -> E,
+    ) -> Result<Self, E>
+    where
+        Self: Sized,
+    {
+        if predicate(&self) { Ok(self) } else { Err(factory(self)) }
+    }
+
     /// Move a error value into an [Err] variant of a [Result].
     ///
     /// When used in terminal position, `T` can be inferred.
@@ -56,6 +110,28 @@ pub trait IntoResult {
     {
         Err(self)
     }
+
+    /// Moves a value into an [Err] variant of a [Result] if `predicate` returns `true`, and into
+    /// an [Ok] variant otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value as [Err] if `predicate` returns `true`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::IntoResult;
+    ///
+    /// assert_eq!(500.into_err_if(|status| *status >= 500), Err(500));
+    /// assert_eq!(200.into_err_if(|status| *status >= 500), Ok(200));
+    /// ```
+    #[inline]
+    fn into_err_if(self, predicate: impl FnOnce(&Self) -> bool) -> Result<Self, Self>
+    where
+        Self: Sized,
+    {
+        if predicate(&self) { Err(self) } else { Ok(self) }
+    }
 }
 
 /// Implements `IntoResult<T>` for all types.
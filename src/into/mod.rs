@@ -1,5 +1,27 @@
+#[cfg(feature = "alloc")]
+mod collect_all;
+mod into_cell;
+mod into_control_flow;
+#[cfg(feature = "std")]
+mod into_lock;
 mod into_option;
+#[cfg(feature = "alloc")]
+mod into_pointer;
+mod into_poll;
 mod into_result;
+mod ok_or_missing;
+mod try_collect_indexed;
 
+#[cfg(feature = "alloc")]
+pub use collect_all::CollectAll;
+pub use into_cell::IntoCell;
+pub use into_control_flow::{ControlFlowIntoResult, IntoControlFlow, ResultIntoControlFlow};
+#[cfg(feature = "std")]
+pub use into_lock::IntoLock;
 pub use into_option::IntoOption;
+#[cfg(feature = "alloc")]
+pub use into_pointer::IntoPointer;
+pub use into_poll::IntoPoll;
 pub use into_result::IntoResult;
+pub use ok_or_missing::{Missing, MissingAt, OkOrMissing};
+pub use try_collect_indexed::{AtIndex, EnumerateErrs, TryCollectIndexed};
@@ -0,0 +1,111 @@
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+/// Provides postfix conversion of an [Option] into a [Result] with a typed "missing value" error.
+#[sealed::sealed]
+pub trait OkOrMissing<T> {
+    /// Converts the [Option] into a [Result], with a [Missing] error carrying `name` if it is
+    /// [None].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Missing(name)`](Missing) if the value is [None].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::{Missing, OkOrMissing};
+    ///
+    /// let port: Option<u16> = None;
+    /// assert_eq!(port.ok_or_missing("port"), Err(Missing("port")));
+    /// assert_eq!(Some(8080).ok_or_missing("port"), Ok(8080));
+    /// assert_eq!(Missing("port").to_string(), "missing value for `port`");
+    /// ```
+    fn ok_or_missing(self, name: &'static str) -> Result<T, Missing>;
+
+    /// Converts the [Option] into a [Result], with a [MissingAt] error carrying `index` if it is
+    /// [None].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MissingAt(index)`](MissingAt) if the value is [None].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::{MissingAt, OkOrMissing};
+    ///
+    /// let fields = ["alice", "21"];
+    /// assert_eq!(fields.get(1).ok_or_missing_at(1), Ok(&"21"));
+    /// assert_eq!(fields.get(2).ok_or_missing_at(2), Err(MissingAt(2)));
+    /// assert_eq!(MissingAt(2).to_string(), "missing value at index 2");
+    /// ```
+    fn ok_or_missing_at(self, index: usize) -> Result<T, MissingAt>;
+
+    /// Converts the [Option] into a [Result], with a [Missing] error carrying `key` formatted as a
+    /// [String] if it is [None].
+    ///
+    /// `key` is only formatted if the value is [None].
+    ///
+    /// # Errors
+    ///
+    /// Returns [Missing] with the formatted `key` if the value is [None].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fluent_result::into::{Missing, OkOrMissing};
+    ///
+    /// let section = "server";
+    /// let port: Option<u16> = None;
+    /// assert_eq!(port.ok_or_missing_key(format_args!("{section}.port")), Err(Missing("server.port".to_string())));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn ok_or_missing_key(self, key: impl Display) -> Result<T, Missing<String>>;
+}
+
+#[sealed::sealed]
+impl<T> OkOrMissing<T> for Option<T> {
+    #[inline]
+    fn ok_or_missing(self, name: &'static str) -> Result<T, Missing> {
+        self.ok_or(Missing(name))
+    }
+
+    #[inline]
+    fn ok_or_missing_at(self, index: usize) -> Result<T, MissingAt> {
+        self.ok_or(MissingAt(index))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn ok_or_missing_key(self, key: impl Display) -> Result<T, Missing<String>> {
+        self.ok_or_else(|| Missing(key.to_string()))
+    }
+}
+
+/// An error created by [`OkOrMissing`] when a named value is missing.
+///
+/// By default the name is a `&'static str`. With the `alloc` feature, `Missing<String>` is created
+/// by [`OkOrMissing::ok_or_missing_key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Missing<K = &'static str>(pub K);
+
+impl<K: Display> Display for Missing<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "missing value for `{}`", self.0)
+    }
+}
+
+impl<K: Display + Debug> Error for Missing<K> {}
+
+/// An error created by [`OkOrMissing::ok_or_missing_at`] when a positional value is missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MissingAt(pub usize);
+
+impl Display for MissingAt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "missing value at index {}", self.0)
+    }
+}
+
+impl Error for MissingAt {}
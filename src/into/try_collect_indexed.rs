@@ -0,0 +1,106 @@
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::iter::FusedIterator;
+
+/// An extension trait for iterators of [`Result`]s, that reports the index of the element that
+/// failed.
+#[sealed::sealed]
+pub trait TryCollectIndexed<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects the [`Ok`] values into `C`, stopping at the first [`Err`] and returning it together
+    /// with its index.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error, wrapped in an [`AtIndex`] with its index in the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::into::TryCollectIndexed;
+    ///
+    /// let numbers = ["1", "2", "x", "y"].iter().map(|s| s.parse::<u32>()).try_collect_indexed::<Vec<_>>();
+    /// let err = numbers.unwrap_err();
+    /// assert_eq!(err.index, 2);
+    /// assert_eq!(err.error.to_string(), "invalid digit found in string");
+    /// ```
+    fn try_collect_indexed<C: FromIterator<T>>(self) -> Result<C, AtIndex<E>>;
+
+    /// Wraps each [`Err`] in an [`AtIndex`] with its index in the iterator, lazily.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::into::{AtIndex, TryCollectIndexed};
+    ///
+    /// let results = [Ok(1), Err("fail"), Ok(2)];
+    /// let indexed: Vec<_> = results.into_iter().enumerate_errs().collect();
+    /// assert_eq!(indexed, [Ok(1), Err(AtIndex { index: 1, error: "fail" }), Ok(2)]);
+    /// ```
+    fn enumerate_errs(self) -> EnumerateErrs<Self>;
+}
+
+#[sealed::sealed]
+impl<T, E, I: Iterator<Item = Result<T, E>>> TryCollectIndexed<T, E> for I {
+    #[inline]
+    fn try_collect_indexed<C: FromIterator<T>>(self) -> Result<C, AtIndex<E>> {
+        self.enumerate_errs().collect()
+    }
+
+    #[inline]
+    fn enumerate_errs(self) -> EnumerateErrs<Self> {
+        EnumerateErrs { iter: self, index: 0 }
+    }
+}
+
+/// An iterator that wraps each [`Err`] in an [`AtIndex`], created by
+/// [`TryCollectIndexed::enumerate_errs`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EnumerateErrs<I> {
+    iter: I,
+    index: usize,
+}
+
+impl<T, E, I: Iterator<Item = Result<T, E>>> Iterator for EnumerateErrs<I> {
+    type Item = Result<T, AtIndex<E>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.iter.next()?;
+        let index = self.index;
+        self.index += 1;
+        Some(result.map_err(|error| AtIndex { index, error }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, E, I: ExactSizeIterator<Item = Result<T, E>>> ExactSizeIterator for EnumerateErrs<I> {}
+
+impl<T, E, I: FusedIterator<Item = Result<T, E>>> FusedIterator for EnumerateErrs<I> {}
+
+/// An error created by [`TryCollectIndexed`], wrapping the error of the element at `index`.
+///
+/// The wrapped error is the [`source`](Error::source) of this error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AtIndex<E> {
+    /// The index of the element that failed.
+    pub index: usize,
+    /// The error of the element that failed.
+    pub error: E,
+}
+
+impl<E> Display for AtIndex<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "error at index {}", self.index)
+    }
+}
+
+impl<E: Error + 'static> Error for AtIndex<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
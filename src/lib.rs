@@ -10,15 +10,21 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg(doc)]
+#[cfg(any(feature = "std", doc))]
 extern crate std;
 
-/// Extension traits for `bool` values.
+/// Extension traits for `bool` values and other predicates.
 pub mod bool;
 
+/// Ready-made error types.
+pub mod error;
+
 /// Extension traits for panic unwrapping [`Result`] and [`Option`] types.
 pub mod expect;
 
+/// Guards on comparable values, returning structured errors.
+pub mod guard;
+
 /// Extension traits for converting to [`Result`] and [`Option`] types.
 pub mod into;
 
@@ -26,4 +32,37 @@ pub mod into;
 pub mod nested;
 
 /// Extension traits for [`Result`] and [`Option`] types.
+///
+/// Methods that sink into a closure, such as `sink_err`, have an `_into` counterpart, such as
+/// `sink_err_into`, that accepts any [`Sink`](sink::Sink) or [`SinkMut`](sink::SinkMut). Closures
+/// implement both traits, but a closure passed to a generic `Sink` parameter does not get its
+/// argument type inferred, so `|err| errors.push(err.len())` would need an annotation.
 pub mod sink;
+
+/// Assertion macros and functions for testing [`Result`] and [`Option`] values.
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::format;
+
+    /// Returns `true` if a stringified literal is a string literal with braces, which would need
+    /// to be formatted.
+    #[must_use]
+    pub const fn is_format_string(literal: &str) -> bool {
+        let bytes = literal.as_bytes();
+        if bytes.is_empty() || (bytes[0] != b'"' && bytes[0] != b'r') {
+            return false;
+        }
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b'{' || bytes[index] == b'}' {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+}
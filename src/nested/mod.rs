@@ -1,7 +1,11 @@
 #[cfg(feature = "alloc")]
 mod box_err;
 mod flatten_err;
+mod nested_iter;
 
 #[cfg(feature = "alloc")]
 pub use box_err::BoxErr;
 pub use flatten_err::{FlattenErr, NestedError};
+#[cfg(feature = "alloc")]
+pub use nested_iter::BoxErrs;
+pub use nested_iter::{FlattenErrs, FlattenOkIter, NestedIter};
@@ -0,0 +1,202 @@
+use core::iter::FusedIterator;
+#[cfg(feature = "alloc")]
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use crate::nested::BoxErr;
+use crate::nested::{FlattenErr, NestedError};
+
+/// An extension trait for iterators of nested [`Result`]s, that flattens each item lazily.
+#[sealed::sealed]
+pub trait NestedIter: Iterator + Sized {
+    /// Flattens each [`Result<Result<T, EIn>, EOut>`] into a [`Result<T, NestedError<EIn, EOut>>`],
+    /// see [`FlattenErr::flatten_err`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::nested::{NestedError, NestedIter};
+    ///
+    /// let results: [Result<Result<u32, &str>, i32>; 3] = [Ok(Ok(1)), Ok(Err("parse")), Err(2)];
+    /// let flattened: Vec<_> = results.into_iter().flatten_errs().collect();
+    /// assert_eq!(flattened, [Ok(1), Err(NestedError::Inner("parse")), Err(NestedError::Outer(2))]);
+    /// ```
+    fn flatten_errs<T, EIn, EOut>(self) -> FlattenErrs<Self>
+    where
+        Self: Iterator<Item = Result<Result<T, EIn>, EOut>>;
+
+    /// Boxes the errors of each [`Result`], flattening up to four layers of nesting, see
+    /// [`BoxErr::box_err`].
+    ///
+    /// Like [`BoxErr::box_err`], the success type may need to be annotated for nested results.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fmt;
+    /// use std::io;
+    /// use fluent_result::nested::NestedIter;
+    ///
+    /// let results: [Result<Result<u32, fmt::Error>, io::Error>; 2] = [Ok(Ok(1)), Ok(Err(fmt::Error))];
+    /// let mut boxed = results.into_iter().box_errs::<u32>();
+    /// assert_eq!(boxed.next().map(Result::ok), Some(Some(1)));
+    /// assert!(boxed.next().is_some_and(|result| result.unwrap_err().is::<fmt::Error>()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn box_errs<T>(self) -> BoxErrs<Self, T>
+    where
+        Self::Item: BoxErr<T>;
+
+    /// Flattens the collection in each [`Ok`] into its items, passing each [`Err`] through.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::nested::NestedIter;
+    ///
+    /// let batches = [Ok(vec![1, 2]), Err("read"), Ok(vec![]), Ok(vec![3])];
+    /// let items: Vec<_> = batches.into_iter().flatten_ok_iter().collect();
+    /// assert_eq!(items, [Ok(1), Ok(2), Err("read"), Ok(3)]);
+    /// ```
+    fn flatten_ok_iter<U, E>(self) -> FlattenOkIter<Self, U>
+    where
+        Self: Iterator<Item = Result<U, E>>,
+        U: IntoIterator;
+}
+
+#[sealed::sealed]
+impl<I: Iterator> NestedIter for I {
+    #[inline]
+    fn flatten_errs<T, EIn, EOut>(self) -> FlattenErrs<Self>
+    where
+        Self: Iterator<Item = Result<Result<T, EIn>, EOut>>,
+    {
+        FlattenErrs { iter: self }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn box_errs<T>(self) -> BoxErrs<Self, T>
+    where
+        Self::Item: BoxErr<T>,
+    {
+        BoxErrs { iter: self, marker: PhantomData }
+    }
+
+    #[inline]
+    fn flatten_ok_iter<U, E>(self) -> FlattenOkIter<Self, U>
+    where
+        Self: Iterator<Item = Result<U, E>>,
+        U: IntoIterator,
+    {
+        FlattenOkIter { iter: self, inner: None }
+    }
+}
+
+/// An iterator that flattens each nested [`Result`], created by [`NestedIter::flatten_errs`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FlattenErrs<I> {
+    iter: I,
+}
+
+impl<T, EIn, EOut, I: Iterator<Item = Result<Result<T, EIn>, EOut>>> Iterator for FlattenErrs<I> {
+    type Item = Result<T, NestedError<EIn, EOut>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(FlattenErr::flatten_err)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, EIn, EOut, I: DoubleEndedIterator<Item = Result<Result<T, EIn>, EOut>>> DoubleEndedIterator for FlattenErrs<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(FlattenErr::flatten_err)
+    }
+}
+
+impl<T, EIn, EOut, I: ExactSizeIterator<Item = Result<Result<T, EIn>, EOut>>> ExactSizeIterator for FlattenErrs<I> {}
+
+impl<T, EIn, EOut, I: FusedIterator<Item = Result<Result<T, EIn>, EOut>>> FusedIterator for FlattenErrs<I> {}
+
+/// An iterator that boxes the errors of each [`Result`], created by [`NestedIter::box_errs`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct BoxErrs<I, T> {
+    iter: I,
+    marker: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, I: Iterator<Item: BoxErr<T>>> Iterator for BoxErrs<I, T> {
+    type Item = Result<T, alloc::boxed::Box<dyn core::error::Error>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(BoxErr::box_err)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, I: DoubleEndedIterator<Item: BoxErr<T>>> DoubleEndedIterator for BoxErrs<I, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(BoxErr::box_err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, I: ExactSizeIterator<Item: BoxErr<T>>> ExactSizeIterator for BoxErrs<I, T> {}
+
+#[cfg(feature = "alloc")]
+impl<T, I: FusedIterator<Item: BoxErr<T>>> FusedIterator for BoxErrs<I, T> {}
+
+/// An iterator that flattens the collection in each [`Ok`], created by
+/// [`NestedIter::flatten_ok_iter`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FlattenOkIter<I, U: IntoIterator> {
+    iter: I,
+    inner: Option<U::IntoIter>,
+}
+
+impl<E, U: IntoIterator, I: Iterator<Item = Result<U, E>>> Iterator for FlattenOkIter<I, U> {
+    type Item = Result<U::Item, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.inner.as_mut().and_then(Iterator::next) {
+                return Some(Ok(item));
+            }
+            self.inner = None;
+            match self.iter.next()? {
+                Ok(collection) => self.inner = Some(collection.into_iter()),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.as_ref().map_or((0, Some(0)), Iterator::size_hint);
+        match self.iter.size_hint() {
+            (_, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
+}
+
+impl<E, U: IntoIterator, I: FusedIterator<Item = Result<U, E>>> FusedIterator for FlattenOkIter<I, U> {}
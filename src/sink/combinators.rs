@@ -0,0 +1,276 @@
+use crate::sink::{Sink, SinkMut};
+
+/// A [`Sink`] that transforms values before sinking them, created by [`Sink::map`].
+#[derive(Debug, Clone)]
+#[must_use = "sinks do nothing unless values are sunk into them"]
+pub struct Map<S, F> {
+    sink: S,
+    map: F,
+}
+
+impl<S, F> Map<S, F> {
+    pub(crate) const fn new(sink: S, map: F) -> Self {
+        Self { sink, map }
+    }
+
+    /// Returns the wrapped sink.
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+impl<T, U, S: Sink<T>, F: FnOnce(U) -> T> Sink<U> for Map<S, F> {
+    #[inline]
+    fn accept(self, value: U) {
+        self.sink.accept((self.map)(value));
+    }
+}
+
+impl<T, U, S: SinkMut<T>, F: FnMut(U) -> T> SinkMut<U> for Map<S, F> {
+    #[inline]
+    fn accept_mut(&mut self, value: U) {
+        self.sink.accept_mut((self.map)(value));
+    }
+}
+
+impl<T, U, S: SinkMut<T>, F: FnMut(U) -> T> Sink<U> for &mut Map<S, F> {
+    #[inline]
+    fn accept(self, value: U) {
+        self.accept_mut(value);
+    }
+}
+
+/// A [`Sink`] that only sinks values matching a predicate, created by [`Sink::filter`].
+#[derive(Debug, Clone)]
+#[must_use = "sinks do nothing unless values are sunk into them"]
+pub struct Filter<S, P> {
+    sink: S,
+    predicate: P,
+}
+
+impl<S, P> Filter<S, P> {
+    pub(crate) const fn new(sink: S, predicate: P) -> Self {
+        Self { sink, predicate }
+    }
+
+    /// Returns the wrapped sink.
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+impl<T, S: Sink<T>, P: FnOnce(&T) -> bool> Sink<T> for Filter<S, P> {
+    #[inline]
+    fn accept(self, value: T) {
+        if (self.predicate)(&value) {
+            self.sink.accept(value);
+        }
+    }
+}
+
+impl<T, S: SinkMut<T>, P: FnMut(&T) -> bool> SinkMut<T> for Filter<S, P> {
+    #[inline]
+    fn accept_mut(&mut self, value: T) {
+        if (self.predicate)(&value) {
+            self.sink.accept_mut(value);
+        }
+    }
+}
+
+impl<T, S: SinkMut<T>, P: FnMut(&T) -> bool> Sink<T> for &mut Filter<S, P> {
+    #[inline]
+    fn accept(self, value: T) {
+        self.accept_mut(value);
+    }
+}
+
+/// A [`Sink`] that sinks each value into two sinks, created by [`Sink::tee`].
+#[derive(Debug, Clone)]
+#[must_use = "sinks do nothing unless values are sunk into them"]
+pub struct Tee<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Tee<A, B> {
+    pub(crate) const fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Returns the wrapped sinks.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<T: Clone, A: Sink<T>, B: Sink<T>> Sink<T> for Tee<A, B> {
+    #[inline]
+    fn accept(self, value: T) {
+        self.first.accept(value.clone());
+        self.second.accept(value);
+    }
+}
+
+impl<T: Clone, A: SinkMut<T>, B: SinkMut<T>> SinkMut<T> for Tee<A, B> {
+    #[inline]
+    fn accept_mut(&mut self, value: T) {
+        self.first.accept_mut(value.clone());
+        self.second.accept_mut(value);
+    }
+}
+
+impl<T: Clone, A: SinkMut<T>, B: SinkMut<T>> Sink<T> for &mut Tee<A, B> {
+    #[inline]
+    fn accept(self, value: T) {
+        self.accept_mut(value);
+    }
+}
+
+/// A [`Sink`] that only sinks the first `n` values, created by [`Sink::take`] and
+/// [`Sink::first_only`].
+///
+/// Values after the first `n` are dropped.
+#[derive(Debug, Clone)]
+#[must_use = "sinks do nothing unless values are sunk into them"]
+pub struct Take<S> {
+    sink: S,
+    remaining: usize,
+}
+
+impl<S> Take<S> {
+    pub(crate) const fn new(sink: S, n: usize) -> Self {
+        Self { sink, remaining: n }
+    }
+
+    /// The number of values that will still be sunk.
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Returns the wrapped sink.
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+impl<T, S: Sink<T>> Sink<T> for Take<S> {
+    #[inline]
+    fn accept(self, value: T) {
+        if self.remaining > 0 {
+            self.sink.accept(value);
+        }
+    }
+}
+
+impl<T, S: SinkMut<T>> SinkMut<T> for Take<S> {
+    #[inline]
+    fn accept_mut(&mut self, value: T) {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            self.sink.accept_mut(value);
+        }
+    }
+}
+
+impl<T, S: SinkMut<T>> Sink<T> for &mut Take<S> {
+    #[inline]
+    fn accept(self, value: T) {
+        self.accept_mut(value);
+    }
+}
+
+/// A [`Sink`] that drops values whose key equals the key of the previous value, created by
+/// [`Sink::dedup_by_key`].
+///
+/// Like `Vec::dedup_by_key`, only consecutive duplicates are dropped.
+#[derive(Debug, Clone)]
+#[must_use = "sinks do nothing unless values are sunk into them"]
+pub struct DedupByKey<S, F, K> {
+    sink: S,
+    key: F,
+    last: Option<K>,
+}
+
+impl<S, F, K> DedupByKey<S, F, K> {
+    pub(crate) const fn new(sink: S, key: F) -> Self {
+        Self { sink, key, last: None }
+    }
+
+    /// Returns the wrapped sink.
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+impl<T, S: Sink<T>, F, K> Sink<T> for DedupByKey<S, F, K> {
+    #[inline]
+    fn accept(self, value: T) {
+        self.sink.accept(value);
+    }
+}
+
+impl<T, S: SinkMut<T>, F: FnMut(&T) -> K, K: PartialEq> SinkMut<T> for DedupByKey<S, F, K> {
+    #[inline]
+    fn accept_mut(&mut self, value: T) {
+        let key = (self.key)(&value);
+        if self.last.as_ref() != Some(&key) {
+            self.last = Some(key);
+            self.sink.accept_mut(value);
+        }
+    }
+}
+
+impl<T, S: SinkMut<T>, F: FnMut(&T) -> K, K: PartialEq> Sink<T> for &mut DedupByKey<S, F, K> {
+    #[inline]
+    fn accept(self, value: T) {
+        self.accept_mut(value);
+    }
+}
+
+/// A [`Sink`] that counts the values sunk into it, created by [`Sink::counted`].
+#[derive(Debug, Clone)]
+#[must_use = "sinks do nothing unless values are sunk into them"]
+pub struct Counted<S> {
+    sink: S,
+    count: usize,
+}
+
+impl<S> Counted<S> {
+    pub(crate) const fn new(sink: S) -> Self {
+        Self { sink, count: 0 }
+    }
+
+    /// The number of values sunk so far.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the wrapped sink.
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+impl<T, S: Sink<T>> Sink<T> for Counted<S> {
+    #[inline]
+    fn accept(self, value: T) {
+        self.sink.accept(value);
+    }
+}
+
+impl<T, S: SinkMut<T>> SinkMut<T> for Counted<S> {
+    #[inline]
+    fn accept_mut(&mut self, value: T) {
+        self.count += 1;
+        self.sink.accept_mut(value);
+    }
+}
+
+impl<T, S: SinkMut<T>> Sink<T> for &mut Counted<S> {
+    #[inline]
+    fn accept(self, value: T) {
+        self.accept_mut(value);
+    }
+}
@@ -1,5 +1,11 @@
+mod combinators;
+mod sink_iter;
 mod sink_option;
 mod sink_result;
+mod target;
 
+pub use combinators::{Counted, DedupByKey, Filter, Map, Take, Tee};
+pub use sink_iter::{SinkErrs, SinkOks, SinkOptionIter, SinkResultIter, SinkSomes};
 pub use sink_option::SinkOption;
 pub use sink_result::SinkResult;
+pub use target::{Sink, SinkMut, WriteSink};
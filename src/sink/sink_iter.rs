@@ -0,0 +1,345 @@
+use core::iter::FusedIterator;
+
+use crate::sink::SinkMut;
+
+/// An extension trait for iterators of [`Result`]s, that routes one variant into a closure or a
+/// [`SinkMut`] while the other keeps flowing.
+#[sealed::sealed]
+pub trait SinkResultIter<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Sinks each [`Err`] into `sink`, yielding the [`Ok`] values.
+    ///
+    /// This is lazy, errors are only sunk as the returned iterator is advanced. To sink into any
+    /// [`SinkMut`], such as a `&mut Vec<E>`, see [`SinkResultIter::sink_errs_into`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkResultIter;
+    ///
+    /// let mut errors = Vec::new();
+    /// let numbers: Vec<u32> = ["1", "x", "3"].iter().map(|s| s.parse()).sink_errs(|err| errors.push(err)).collect();
+    /// assert_eq!(numbers, [1, 3]);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    fn sink_errs<F: FnMut(E)>(self, sink: F) -> SinkErrs<Self, F>;
+
+    /// Sinks each [`Err`] into any [`SinkMut`], yielding the [`Ok`] values.
+    ///
+    /// This is lazy, errors are only sunk as the returned iterator is advanced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkResultIter;
+    ///
+    /// let mut errors = Vec::new();
+    /// let numbers: Vec<u32> = ["1", "x", "3"].iter().map(|s| s.parse()).sink_errs_into(&mut errors).collect();
+    /// assert_eq!(numbers, [1, 3]);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    fn sink_errs_into<S: SinkMut<E>>(self, sink: S) -> SinkErrs<Self, S>;
+
+    /// Sinks each [`Ok`] into `sink`, yielding the [`Err`] values.
+    ///
+    /// This is lazy, values are only sunk as the returned iterator is advanced. To sink into any
+    /// [`SinkMut`], such as a `&mut usize`, see [`SinkResultIter::sink_oks_into`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkResultIter;
+    ///
+    /// let mut sum = 0;
+    /// let results = [Ok(1), Err("fail"), Ok(2)];
+    /// let errors: Vec<_> = results.into_iter().sink_oks(|value| sum += value).collect();
+    /// assert_eq!(errors, ["fail"]);
+    /// assert_eq!(sum, 3);
+    /// ```
+    fn sink_oks<F: FnMut(T)>(self, sink: F) -> SinkOks<Self, F>;
+
+    /// Sinks each [`Ok`] into any [`SinkMut`], yielding the [`Err`] values.
+    ///
+    /// This is lazy, values are only sunk as the returned iterator is advanced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkResultIter;
+    ///
+    /// let mut successes = 0;
+    /// let results = [Ok(1), Err("fail"), Ok(2)];
+    /// let errors: Vec<_> = results.into_iter().sink_oks_into(&mut successes).collect();
+    /// assert_eq!(errors, ["fail"]);
+    /// assert_eq!(successes, 2);
+    /// ```
+    fn sink_oks_into<S: SinkMut<T>>(self, sink: S) -> SinkOks<Self, S>;
+
+    /// Consumes the iterator, sinking each [`Ok`] into `ok_sink` and each [`Err`] into
+    /// `err_sink`.
+    ///
+    /// Returns the number of [`Ok`] and [`Err`] values, in that order. To sink into any
+    /// [`SinkMut`], see [`SinkResultIter::drain_into`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkResultIter;
+    ///
+    /// let (mut values, mut errors) = (Vec::new(), Vec::new());
+    /// let results = [Ok(1), Err("fail"), Ok(2)];
+    /// let counts = results.into_iter().drain(|value| values.push(value), |err| errors.push(err));
+    /// assert_eq!(counts, (2, 1));
+    /// assert_eq!(values, [1, 2]);
+    /// assert_eq!(errors, ["fail"]);
+    /// ```
+    fn drain<O: FnMut(T), F: FnMut(E)>(self, ok_sink: O, err_sink: F) -> (usize, usize);
+
+    /// Consumes the iterator, sinking each [`Ok`] into `ok_sink` and each [`Err`] into
+    /// `err_sink`, which can be any [`SinkMut`].
+    ///
+    /// Returns the number of [`Ok`] and [`Err`] values, in that order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkResultIter;
+    ///
+    /// let (mut values, mut errors) = (Vec::new(), Vec::new());
+    /// let results = [Ok(1), Err("fail"), Ok(2)];
+    /// let counts = results.into_iter().drain_into(&mut values, &mut errors);
+    /// assert_eq!(counts, (2, 1));
+    /// assert_eq!(values, [1, 2]);
+    /// assert_eq!(errors, ["fail"]);
+    /// ```
+    fn drain_into<O: SinkMut<T>, S: SinkMut<E>>(self, ok_sink: O, err_sink: S) -> (usize, usize);
+}
+
+#[sealed::sealed]
+impl<T, E, I: Iterator<Item = Result<T, E>>> SinkResultIter<T, E> for I {
+    #[inline]
+    fn sink_errs<F: FnMut(E)>(self, sink: F) -> SinkErrs<Self, F> {
+        self.sink_errs_into(sink)
+    }
+
+    #[inline]
+    fn sink_errs_into<S: SinkMut<E>>(self, sink: S) -> SinkErrs<Self, S> {
+        SinkErrs { iter: self, sink }
+    }
+
+    #[inline]
+    fn sink_oks<F: FnMut(T)>(self, sink: F) -> SinkOks<Self, F> {
+        self.sink_oks_into(sink)
+    }
+
+    #[inline]
+    fn sink_oks_into<S: SinkMut<T>>(self, sink: S) -> SinkOks<Self, S> {
+        SinkOks { iter: self, sink }
+    }
+
+    #[inline]
+    fn drain<O: FnMut(T), F: FnMut(E)>(self, ok_sink: O, err_sink: F) -> (usize, usize) {
+        self.drain_into(ok_sink, err_sink)
+    }
+
+    #[inline]
+    fn drain_into<O: SinkMut<T>, S: SinkMut<E>>(self, mut ok_sink: O, mut err_sink: S) -> (usize, usize) {
+        self.fold((0, 0), |(oks, errs), result| match result {
+            Ok(value) => {
+                ok_sink.accept_mut(value);
+                (oks + 1, errs)
+            }
+            Err(err) => {
+                err_sink.accept_mut(err);
+                (oks, errs + 1)
+            }
+        })
+    }
+}
+
+/// An extension trait for iterators of [`Option`]s, that routes the [`Some`] values into a
+/// closure or a [`SinkMut`].
+#[sealed::sealed]
+pub trait SinkOptionIter<T>: Iterator<Item = Option<T>> + Sized {
+    /// Sinks each [`Some`] into `sink`, yielding the position of each [`None`].
+    ///
+    /// This is lazy, values are only sunk as the returned iterator is advanced. Positions count
+    /// every item of the original iterator, like [`Iterator::enumerate`]. To sink into any
+    /// [`SinkMut`], see [`SinkOptionIter::sink_somes_into`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkOptionIter;
+    ///
+    /// let mut errors = Vec::new();
+    /// let checks = [None, Some("name is empty"), None];
+    /// let passed: Vec<usize> = checks.into_iter().sink_somes(|err| errors.push(err)).collect();
+    /// assert_eq!(passed, [0, 2]);
+    /// assert_eq!(errors, ["name is empty"]);
+    /// ```
+    fn sink_somes<F: FnMut(T)>(self, sink: F) -> SinkSomes<Self, F>;
+
+    /// Sinks each [`Some`] into any [`SinkMut`], yielding the position of each [`None`].
+    ///
+    /// This is lazy, values are only sunk as the returned iterator is advanced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkOptionIter;
+    ///
+    /// let mut errors = Vec::new();
+    /// let checks = [None, Some("name is empty"), None];
+    /// let passed = checks.into_iter().sink_somes_into(&mut errors).count();
+    /// assert_eq!(passed, 2);
+    /// assert_eq!(errors, ["name is empty"]);
+    /// ```
+    fn sink_somes_into<S: SinkMut<T>>(self, sink: S) -> SinkSomes<Self, S>;
+}
+
+#[sealed::sealed]
+impl<T, I: Iterator<Item = Option<T>>> SinkOptionIter<T> for I {
+    #[inline]
+    fn sink_somes<F: FnMut(T)>(self, sink: F) -> SinkSomes<Self, F> {
+        self.sink_somes_into(sink)
+    }
+
+    #[inline]
+    fn sink_somes_into<S: SinkMut<T>>(self, sink: S) -> SinkSomes<Self, S> {
+        SinkSomes { iter: self, sink, position: 0 }
+    }
+}
+
+/// An iterator that sinks [`Err`]s and yields [`Ok`] values, created by
+/// [`SinkResultIter::sink_errs`] and [`SinkResultIter::sink_errs_into`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SinkErrs<I, S> {
+    iter: I,
+    sink: S,
+}
+
+impl<T, E, I: Iterator<Item = Result<T, E>>, S: SinkMut<E>> Iterator for SinkErrs<I, S> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        loop {
+            match self.iter.next()? {
+                Ok(value) => return Some(value),
+                Err(err) => self.sink.accept_mut(err),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<T, E, I: DoubleEndedIterator<Item = Result<T, E>>, S: SinkMut<E>> DoubleEndedIterator for SinkErrs<I, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        loop {
+            match self.iter.next_back()? {
+                Ok(value) => return Some(value),
+                Err(err) => self.sink.accept_mut(err),
+            }
+        }
+    }
+}
+
+impl<T, E, I: FusedIterator<Item = Result<T, E>>, S: SinkMut<E>> FusedIterator for SinkErrs<I, S> {}
+
+/// An iterator that sinks [`Ok`] values and yields [`Err`]s, created by
+/// [`SinkResultIter::sink_oks`] and [`SinkResultIter::sink_oks_into`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SinkOks<I, S> {
+    iter: I,
+    sink: S,
+}
+
+impl<T, E, I: Iterator<Item = Result<T, E>>, S: SinkMut<T>> Iterator for SinkOks<I, S> {
+    type Item = E;
+
+    #[inline]
+    fn next(&mut self) -> Option<E> {
+        loop {
+            match self.iter.next()? {
+                Ok(value) => self.sink.accept_mut(value),
+                Err(err) => return Some(err),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<T, E, I: DoubleEndedIterator<Item = Result<T, E>>, S: SinkMut<T>> DoubleEndedIterator for SinkOks<I, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<E> {
+        loop {
+            match self.iter.next_back()? {
+                Ok(value) => self.sink.accept_mut(value),
+                Err(err) => return Some(err),
+            }
+        }
+    }
+}
+
+impl<T, E, I: FusedIterator<Item = Result<T, E>>, S: SinkMut<T>> FusedIterator for SinkOks<I, S> {}
+
+/// An iterator that sinks [`Some`] values and yields the position of each [`None`], created by
+/// [`SinkOptionIter::sink_somes`] and [`SinkOptionIter::sink_somes_into`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SinkSomes<I, S> {
+    iter: I,
+    sink: S,
+    position: usize,
+}
+
+impl<T, I: Iterator<Item = Option<T>>, S: SinkMut<T>> Iterator for SinkSomes<I, S> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let item = self.iter.next()?;
+            let position = self.position;
+            self.position += 1;
+            match item {
+                Some(value) => self.sink.accept_mut(value),
+                None => return Some(position),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<T, I, S> DoubleEndedIterator for SinkSomes<I, S>
+where
+    I: DoubleEndedIterator<Item = Option<T>> + ExactSizeIterator,
+    S: SinkMut<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<usize> {
+        loop {
+            match self.iter.next_back()? {
+                Some(value) => self.sink.accept_mut(value),
+                None => return Some(self.position + self.iter.len()),
+            }
+        }
+    }
+}
+
+impl<T, I: FusedIterator<Item = Option<T>>, S: SinkMut<T>> FusedIterator for SinkSomes<I, S> {}
@@ -1,8 +1,12 @@
+use crate::sink::Sink;
+
 /// An extension for handling [`Some`] variants by sinking them into a side-effecting function.
 #[sealed::sealed]
 pub trait SinkOption<T> {
     /// Handles [`Some`] variants of [`Option`]s by sinking them into `sink`.
     ///
+    /// To sink into any [`Sink`], such as a `&mut Vec<T>`, see [`SinkOption::sink_into`].
+    ///
     /// # Example
     ///
     /// ```rust
@@ -15,6 +19,21 @@ pub trait SinkOption<T> {
     fn sink<F>(self, sink: F)
     where
         F: FnOnce(T);
+
+    /// Handles [`Some`] variants of [`Option`]s by sinking them into any [`Sink`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkOption;
+    ///
+    /// let mut errors = Vec::new();
+    /// Some("oops").sink_into(&mut errors);
+    /// assert_eq!(errors, ["oops"]);
+    /// ```
+    fn sink_into<S>(self, sink: S)
+    where
+        S: Sink<T>;
 }
 
 #[sealed::sealed]
@@ -28,4 +47,14 @@ impl<T> SinkOption<T> for Option<T> {
             sink(e);
         }
     }
+
+    #[inline]
+    fn sink_into<S>(self, sink: S)
+    where
+        S: Sink<T>,
+    {
+        if let Some(e) = self {
+            sink.accept(e);
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use crate::sink::Sink;
+
 /// Extension trait for sinking a variant of a `Result`, leaving an `Option`.
 ///
 /// This is useful for one-sided handling where you want to consume one variant
@@ -6,6 +8,8 @@
 pub trait SinkResult<T, E> {
     /// Sink the [`Ok`] variant into `sink`, returning the [`Err`] variant, if any.
     ///
+    /// To sink into any [`Sink`], such as a `&mut Vec<T>`, see [`SinkResult::sink_ok_into`].
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -31,6 +35,8 @@ pub trait SinkResult<T, E> {
 
     /// Sink the [`Err`] variant into `sink`, returning the [`Ok`] variant, if any.
     ///
+    /// To sink into any [`Sink`], such as a `&mut Vec<E>`, see [`SinkResult::sink_err_into`].
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -53,6 +59,38 @@ pub trait SinkResult<T, E> {
     fn sink_err<F>(self, sink: F) -> Option<T>
     where
         F: FnOnce(E);
+
+    /// Sink the [`Ok`] variant into any [`Sink`], returning the [`Err`] variant, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkResult;
+    ///
+    /// let mut count = 0;
+    /// assert_eq!(Ok::<u32, &str>(42).sink_ok_into(&mut count), None);
+    /// assert_eq!(Err::<u32, &str>("fail").sink_ok_into(&mut count), Some("fail"));
+    /// assert_eq!(count, 1);
+    /// ```
+    fn sink_ok_into<S>(self, sink: S) -> Option<E>
+    where
+        S: Sink<T>;
+
+    /// Sink the [`Err`] variant into any [`Sink`], returning the [`Ok`] variant, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fluent_result::sink::SinkResult;
+    ///
+    /// let mut errors = Vec::new();
+    /// let values: Vec<u32> = ["1", "x", "3"].iter().filter_map(|s| s.parse().sink_err_into(&mut errors)).collect();
+    /// assert_eq!(values, [1, 3]);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    fn sink_err_into<S>(self, sink: S) -> Option<T>
+    where
+        S: Sink<E>;
 }
 
 #[sealed::sealed]
@@ -80,4 +118,28 @@ impl<T, E> SinkResult<T, E> for Result<T, E> {
         }
         None
     }
+
+    #[inline]
+    fn sink_ok_into<S>(self, sink: S) -> Option<E>
+    where
+        S: Sink<T>,
+    {
+        match self {
+            Ok(t) => sink.accept(t),
+            Err(e) => return Some(e),
+        }
+        None
+    }
+
+    #[inline]
+    fn sink_err_into<S>(self, sink: S) -> Option<T>
+    where
+        S: Sink<E>,
+    {
+        match self {
+            Ok(t) => return Some(t),
+            Err(e) => sink.accept(e),
+        }
+        None
+    }
 }
@@ -0,0 +1,324 @@
+use core::fmt::{Display, Write};
+
+use crate::sink::{Counted, DedupByKey, Filter, Map, Take, Tee};
+
+/// A target that values can be sunk into, such as a closure, a collection or a counter.
+///
+/// [`SinkOption::sink_into`](crate::sink::SinkOption::sink_into),
+/// [`SinkResult::sink_ok_into`](crate::sink::SinkResult::sink_ok_into) and
+/// [`SinkResult::sink_err_into`](crate::sink::SinkResult::sink_err_into) accept any [`Sink`].
+/// Implementations are provided for:
+///
+/// - closures taking the value, that is any [`FnOnce(T)`](FnOnce).
+/// - `&mut Vec<T>`, which pushes the value, with the `alloc` feature.
+/// - `&mut Option<T>`, which keeps the last value.
+/// - `&mut usize`, which counts the values.
+/// - [`WriteSink`], which writes the value to a [`core::fmt::Write`].
+/// - `&Sender<T>`, which sends the value over a [`std::sync::mpsc`] channel, with the `std`
+///   feature.
+///
+/// Sinks can be reshaped with combinators, such as [`Sink::filter`] or [`Sink::take`]. To sink
+/// several values into a combinator, pass it by `&mut`, which requires the sinks it wraps to
+/// implement [`SinkMut`].
+///
+/// Note that `take` and `dedup_by_key` are also inherent methods of [`Option`] and `Vec`, so on
+/// `&mut Option<T>` and `&mut Vec<T>` call them as `Sink::take(&mut last, n)`.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::sink::SinkOption;
+///
+/// let mut errors = Vec::new();
+/// let mut last = None;
+/// let mut failures = 0;
+///
+/// for result in [Ok(1), Err("first"), Err("second")] {
+///     result.err().sink_into(&mut errors);
+///     result.err().sink_into(&mut last);
+///     result.err().sink_into(&mut failures);
+/// }
+/// assert_eq!(errors, ["first", "second"]);
+/// assert_eq!(last, Some("second"));
+/// assert_eq!(failures, 2);
+/// ```
+pub trait Sink<T> {
+    /// Sinks `value` into this target.
+    fn accept(self, value: T);
+
+    /// Transforms each value with `map` before sinking it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::{Sink, SinkResult};
+    ///
+    /// let mut lengths = Vec::new();
+    /// Err::<(), _>("fail").sink_err_into((&mut lengths).map(str::len));
+    /// assert_eq!(lengths, [4]);
+    /// ```
+    fn map<U, F>(self, map: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(U) -> T,
+    {
+        Map::new(self, map)
+    }
+
+    /// Only sinks values for which `predicate` returns `true`, dropping the others.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    /// use fluent_result::sink::{Sink, SinkResult};
+    ///
+    /// let mut errors = Vec::new();
+    /// let mut sink = (&mut errors).filter(|err: &Error| err.kind() != ErrorKind::NotFound);
+    /// Err::<(), _>(Error::from(ErrorKind::NotFound)).sink_err_into(&mut sink);
+    /// Err::<(), _>(Error::from(ErrorKind::PermissionDenied)).sink_err_into(&mut sink);
+    ///
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].kind(), ErrorKind::PermissionDenied);
+    /// ```
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&T) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// Sinks each value into both this sink and `other`, cloning it for this sink.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::{Sink, SinkOption};
+    ///
+    /// let (mut log, mut failures) = (Vec::new(), 0);
+    /// let mut sink = (&mut log).tee(&mut failures);
+    /// Some("first").sink_into(&mut sink);
+    /// Some("second").sink_into(&mut sink);
+    ///
+    /// assert_eq!(log, ["first", "second"]);
+    /// assert_eq!(failures, 2);
+    /// ```
+    fn tee<B>(self, other: B) -> Tee<Self, B>
+    where
+        Self: Sized,
+        T: Clone,
+        B: Sink<T>,
+    {
+        Tee::new(self, other)
+    }
+
+    /// Only sinks the first value, dropping the rest. This is the same as `take(1)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::{Sink, SinkOption};
+    ///
+    /// let mut first = None;
+    /// let mut sink = (&mut first).first_only();
+    /// Some("first").sink_into(&mut sink);
+    /// Some("second").sink_into(&mut sink);
+    /// assert_eq!(first, Some("first"));
+    /// ```
+    fn first_only(self) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, 1)
+    }
+
+    /// Only sinks the first `n` values, dropping the rest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::{Sink, SinkResult};
+    ///
+    /// let mut log = Vec::new();
+    /// let mut sink = (&mut log).take(2);
+    /// for result in [Err(1), Ok(()), Err(2), Err(3)] {
+    ///     result.sink_err_into(&mut sink);
+    /// }
+    /// assert_eq!(log, [1, 2]);
+    /// ```
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, n)
+    }
+
+    /// Drops values whose key, as returned by `key`, equals the key of the previous value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::{Sink, SinkOption};
+    ///
+    /// let mut codes = Vec::new();
+    /// let mut sink = Sink::dedup_by_key(&mut codes, |code: &u16| *code);
+    /// for code in [404, 404, 500, 404] {
+    ///     Some(code).sink_into(&mut sink);
+    /// }
+    /// assert_eq!(codes, [404, 500, 404]);
+    /// ```
+    fn dedup_by_key<K, F>(self, key: F) -> DedupByKey<Self, F, K>
+    where
+        Self: Sized,
+        F: FnMut(&T) -> K,
+        K: PartialEq,
+    {
+        DedupByKey::new(self, key)
+    }
+
+    /// Counts the values sunk into this sink, see [`Counted::count`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fluent_result::sink::{Sink, SinkResult};
+    ///
+    /// let mut log = String::new();
+    /// let mut sink = (|err: &str| log.push_str(err)).counted();
+    /// Err::<(), _>("a").sink_err_into(&mut sink);
+    /// Err::<(), _>("b").sink_err_into(&mut sink);
+    /// assert_eq!(sink.count(), 2);
+    /// assert_eq!(log, "ab");
+    /// ```
+    fn counted(self) -> Counted<Self>
+    where
+        Self: Sized,
+    {
+        Counted::new(self)
+    }
+}
+
+/// A [`Sink`] that can be sunk into several times, through a mutable reference.
+///
+/// The combinators of [`Sink`] implement [`Sink`] by `&mut` when the sinks they wrap implement
+/// [`SinkMut`]. It is implemented for [`FnMut(T)`](FnMut) closures, for the `&mut` and `&`
+/// sinks listed in [`Sink`], for [`WriteSink`] and for the combinators themselves.
+pub trait SinkMut<T> {
+    /// Sinks `value` into this target.
+    fn accept_mut(&mut self, value: T);
+}
+
+impl<T, F: FnMut(T)> SinkMut<T> for F {
+    #[inline]
+    fn accept_mut(&mut self, value: T) {
+        self(value);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SinkMut<T> for &mut alloc::vec::Vec<T> {
+    #[inline]
+    fn accept_mut(&mut self, value: T) {
+        self.push(value);
+    }
+}
+
+impl<T> SinkMut<T> for &mut Option<T> {
+    #[inline]
+    fn accept_mut(&mut self, value: T) {
+        **self = Some(value);
+    }
+}
+
+impl<T> SinkMut<T> for &mut usize {
+    #[inline]
+    fn accept_mut(&mut self, _: T) {
+        **self += 1;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> SinkMut<T> for &std::sync::mpsc::Sender<T> {
+    #[inline]
+    fn accept_mut(&mut self, value: T) {
+        self.accept(value);
+    }
+}
+
+impl<T, F: FnOnce(T)> Sink<T> for F {
+    #[inline]
+    fn accept(self, value: T) {
+        self(value);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Sink<T> for &mut alloc::vec::Vec<T> {
+    #[inline]
+    fn accept(self, value: T) {
+        self.push(value);
+    }
+}
+
+impl<T> Sink<T> for &mut Option<T> {
+    #[inline]
+    fn accept(self, value: T) {
+        *self = Some(value);
+    }
+}
+
+impl<T> Sink<T> for &mut usize {
+    #[inline]
+    fn accept(self, _: T) {
+        *self += 1;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Sink<T> for &std::sync::mpsc::Sender<T> {
+    /// Sends `value` over the channel, dropping it if the receiver has hung up.
+    #[inline]
+    fn accept(self, value: T) {
+        let _ = self.send(value);
+    }
+}
+
+/// A [`Sink`] that writes each value to a [`core::fmt::Write`], such as a `String`, followed by
+/// a newline.
+///
+/// Formatting errors are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::sink::{SinkResult, WriteSink};
+///
+/// let mut log = String::new();
+/// let result: Result<u32, &str> = Err("fail");
+/// result.sink_err_into(WriteSink(&mut log));
+/// assert_eq!(log, "fail\n");
+/// ```
+#[derive(Debug)]
+pub struct WriteSink<W>(pub W);
+
+impl<T: Display, W: Write> Sink<T> for WriteSink<W> {
+    #[inline]
+    fn accept(mut self, value: T) {
+        self.accept_mut(value);
+    }
+}
+
+impl<T: Display, W: Write> SinkMut<T> for WriteSink<W> {
+    #[inline]
+    fn accept_mut(&mut self, value: T) {
+        let _ = writeln!(self.0, "{value}");
+    }
+}
+
+impl<T: Display, W: Write> Sink<T> for &mut WriteSink<W> {
+    #[inline]
+    fn accept(self, value: T) {
+        self.accept_mut(value);
+    }
+}
@@ -0,0 +1,374 @@
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::expect::ErrorReport;
+#[cfg(all(doc, feature = "alloc"))]
+use crate::nested::BoxErr;
+#[cfg(doc)]
+use crate::nested::NestedError;
+
+pub use crate::{assert_err_matches, assert_nested_inner, assert_nested_outer, assert_ok_eq, assert_some_eq};
+
+/// Asserts that a [`Result`] is [`Ok`] and that its value is equal to `expected`.
+///
+/// # Panics
+///
+/// Panics if the result is [`Err`], with the [`Debug`] representation of the error and its
+/// [`source`](Error::source) chain. Panics if the value is not equal to `expected`, with the
+/// [`Debug`] representation of both values.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::testing::assert_ok_eq;
+///
+/// let result: Result<u32, std::num::ParseIntError> = "42".parse();
+/// assert_ok_eq!(result, 42);
+/// ```
+#[macro_export]
+macro_rules! assert_ok_eq {
+    ($result:expr, $expected:expr $(,)?) => {
+        match (&$result, &$expected) {
+            (::core::result::Result::Ok(value), expected) => {
+                ::core::assert_eq!(value, expected, "result was `Ok`, but the value did not match");
+            }
+            (::core::result::Result::Err(error), _) => {
+                ::core::panic!("assertion failed: expected `Ok`, but was `Err`: {}", $crate::__payload!(error));
+            }
+        }
+    };
+}
+
+/// Asserts that a [`Result`] is [`Err`] and that its error matches a pattern, with an optional
+/// guard.
+///
+/// # Panics
+///
+/// Panics if the result is [`Ok`], with the [`Debug`] representation of the value. Panics if the
+/// error does not match, with the [`Debug`] representation of the error and its
+/// [`source`](Error::source) chain.
+///
+/// # Example
+///
+/// ```rust
+/// use std::num::IntErrorKind;
+/// use fluent_result::testing::assert_err_matches;
+///
+/// let result: Result<u32, std::num::ParseIntError> = "".parse();
+/// assert_err_matches!(result, e if *e.kind() == IntErrorKind::Empty);
+/// ```
+#[macro_export]
+macro_rules! assert_err_matches {
+    ($result:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        match &$result {
+            ::core::result::Result::Err(error) => {
+                if !::core::matches!(error, $pattern $(if $guard)?) {
+                    ::core::panic!(
+                        "assertion failed: `Err` did not match `{}`: {}",
+                        ::core::stringify!($pattern $(if $guard)?),
+                        $crate::__payload!(error)
+                    );
+                }
+            }
+            ::core::result::Result::Ok(value) => {
+                ::core::panic!("assertion failed: expected `Err`, but was `Ok`: {:?}", value);
+            }
+        }
+    };
+}
+
+/// Asserts that an [`Option`] is [`Some`] and that its value is equal to `expected`.
+///
+/// # Panics
+///
+/// Panics if the option is [`None`]. Panics if the value is not equal to `expected`, with the
+/// [`Debug`] representation of both values.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::testing::assert_some_eq;
+///
+/// assert_some_eq!([1, 2, 3].first(), &1);
+/// ```
+#[macro_export]
+macro_rules! assert_some_eq {
+    ($option:expr, $expected:expr $(,)?) => {
+        match (&$option, &$expected) {
+            (::core::option::Option::Some(value), expected) => {
+                ::core::assert_eq!(value, expected, "option was `Some`, but the value did not match");
+            }
+            (::core::option::Option::None, _) => {
+                ::core::panic!("assertion failed: expected `Some`, but was `None`");
+            }
+        }
+    };
+}
+
+/// Asserts that a [`Result`] from [`FlattenErr::flatten_err`](crate::nested::FlattenErr::flatten_err)
+/// is a [`NestedError::Inner`] error, evaluating to the inner error.
+///
+/// If a pattern is given, additionally asserts that the inner error matches it.
+///
+/// # Panics
+///
+/// Panics if the result is [`Ok`] or a [`NestedError::Outer`] error, or if the inner error does
+/// not match the pattern. The panic message includes the [`Debug`] representation of the payload
+/// and, for errors, its [`source`](Error::source) chain.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::nested::FlattenErr;
+/// use fluent_result::testing::assert_nested_inner;
+///
+/// let result: Result<Result<u32, &str>, std::fmt::Error> = Ok(Err("oops"));
+/// let inner = assert_nested_inner!(result.flatten_err());
+/// assert_eq!(inner, "oops");
+///
+/// let result: Result<Result<u32, u8>, std::fmt::Error> = Ok(Err(3));
+/// assert_nested_inner!(result.flatten_err(), 1..=5);
+/// ```
+#[macro_export]
+macro_rules! assert_nested_inner {
+    ($result:expr $(,)?) => {
+        $crate::assert_nested_inner!($result, _)
+    };
+    ($result:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        match $result {
+            ::core::result::Result::Err($crate::nested::NestedError::Inner(error)) => {
+                if !::core::matches!(&error, $pattern $(if $guard)?) {
+                    ::core::panic!(
+                        "assertion failed: `NestedError::Inner` did not match `{}`: {}",
+                        ::core::stringify!($pattern $(if $guard)?),
+                        $crate::__payload!(&error)
+                    );
+                }
+                error
+            }
+            ::core::result::Result::Err($crate::nested::NestedError::Outer(error)) => {
+                ::core::panic!(
+                    "assertion failed: expected `NestedError::Inner`, but was `NestedError::Outer`: {}",
+                    $crate::__payload!(&error)
+                );
+            }
+            ::core::result::Result::Ok(value) => {
+                ::core::panic!("assertion failed: expected `NestedError::Inner`, but was `Ok`: {:?}", value);
+            }
+        }
+    };
+}
+
+/// Asserts that a [`Result`] from [`FlattenErr::flatten_err`](crate::nested::FlattenErr::flatten_err)
+/// is a [`NestedError::Outer`] error, evaluating to the outer error.
+///
+/// If a pattern is given, additionally asserts that the outer error matches it.
+///
+/// # Panics
+///
+/// Panics if the result is [`Ok`] or a [`NestedError::Inner`] error, or if the outer error does
+/// not match the pattern. The panic message includes the [`Debug`] representation of the payload
+/// and, for errors, its [`source`](Error::source) chain.
+///
+/// # Example
+///
+/// ```rust
+/// use fluent_result::nested::FlattenErr;
+/// use fluent_result::testing::assert_nested_outer;
+///
+/// let result: Result<Result<u32, &str>, u8> = Err(2);
+/// let outer = assert_nested_outer!(result.flatten_err());
+/// assert_eq!(outer, 2);
+/// ```
+#[macro_export]
+macro_rules! assert_nested_outer {
+    ($result:expr $(,)?) => {
+        $crate::assert_nested_outer!($result, _)
+    };
+    ($result:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        match $result {
+            ::core::result::Result::Err($crate::nested::NestedError::Outer(error)) => {
+                if !::core::matches!(&error, $pattern $(if $guard)?) {
+                    ::core::panic!(
+                        "assertion failed: `NestedError::Outer` did not match `{}`: {}",
+                        ::core::stringify!($pattern $(if $guard)?),
+                        $crate::__payload!(&error)
+                    );
+                }
+                error
+            }
+            ::core::result::Result::Err($crate::nested::NestedError::Inner(error)) => {
+                ::core::panic!(
+                    "assertion failed: expected `NestedError::Outer`, but was `NestedError::Inner`: {}",
+                    $crate::__payload!(&error)
+                );
+            }
+            ::core::result::Result::Ok(value) => {
+                ::core::panic!("assertion failed: expected `NestedError::Outer`, but was `Ok`: {:?}", value);
+            }
+        }
+    };
+}
+
+/// Asserts that a boxed error [`Result`], such as one created by [`BoxErr::box_err`], is an
+/// [`Err`] of type `E`, returning a reference to the downcast error.
+///
+/// The error can be any [`BoxedError`], such as a `Box<dyn Error + Send + Sync>`.
+///
+/// # Panics
+///
+/// Panics if the result is [`Ok`], with the [`Debug`] representation of the value. Panics if the
+/// error is not an `E`, with the [`Debug`] representation of the error and its
+/// [`source`](Error::source) chain.
+///
+/// # Example
+///
+/// ```rust
+/// use std::error::Error;
+/// use fluent_result::nested::BoxErr;
+/// use fluent_result::testing::assert_err_is;
+///
+/// let result: Result<Result<u32, std::fmt::Error>, std::io::Error> = Ok(Err(std::fmt::Error));
+/// let boxed: Result<u32, Box<dyn Error>> = result.box_err();
+/// let error = assert_err_is::<std::fmt::Error>(&boxed);
+/// assert_eq!(error.to_string(), "an error occurred when formatting an argument");
+///
+/// let shared: Result<u32, Box<dyn Error + Send + Sync>> = Err(Box::new(std::fmt::Error));
+/// assert_err_is::<std::fmt::Error>(&shared);
+/// ```
+#[cfg(feature = "alloc")]
+#[track_caller]
+pub fn assert_err_is<E: Error + 'static>(result: &Result<impl Debug, impl BoxedError>) -> &E {
+    match result {
+        Ok(value) => panic!("assertion failed: expected `Err`, but was `Ok`: {value:?}"),
+        Err(error) => {
+            let error = error.as_dyn_error();
+            error.downcast_ref().unwrap_or_else(|| {
+                panic!(
+                    "assertion failed: expected an error of type `{}`: {}",
+                    core::any::type_name::<E>(),
+                    PayloadReport::new(error, Some(error))
+                )
+            })
+        }
+    }
+}
+
+/// A boxed error trait object that [`assert_err_is`] can downcast.
+#[cfg(feature = "alloc")]
+#[sealed::sealed]
+pub trait BoxedError: Debug {
+    /// Returns the boxed error as a `dyn Error`.
+    fn as_dyn_error(&self) -> &(dyn Error + 'static);
+}
+
+#[cfg(feature = "alloc")]
+#[sealed::sealed]
+impl BoxedError for Box<dyn Error> {
+    #[inline]
+    fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+        self.as_ref()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[sealed::sealed]
+impl BoxedError for Box<dyn Error + Send> {
+    #[inline]
+    fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+        self.as_ref()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[sealed::sealed]
+impl BoxedError for Box<dyn Error + Send + Sync> {
+    #[inline]
+    fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+        self.as_ref()
+    }
+}
+
+/// Formats the [`Debug`] representation of an assertion payload, followed by its
+/// [`source`](Error::source) chain if it is an error.
+#[doc(hidden)]
+pub struct PayloadReport<'a> {
+    payload: &'a dyn Debug,
+    error: Option<&'a dyn Error>,
+}
+
+impl<'a> PayloadReport<'a> {
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn new(payload: &'a dyn Debug, error: Option<&'a dyn Error>) -> Self {
+        Self { payload, error }
+    }
+}
+
+impl Display for PayloadReport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.payload)?;
+        self.error.map_or(Ok(()), |error| ErrorReport::new(error).fmt_sources(f))
+    }
+}
+
+/// Autoref-based dispatch, reporting the [`source`](Error::source) chain of payloads that are
+/// errors, and only the [`Debug`] representation of other payloads.
+#[doc(hidden)]
+pub mod __private {
+    use core::error::Error;
+    use core::fmt::Debug;
+
+    use super::PayloadReport;
+
+    pub struct Payload<'a, T>(pub &'a T);
+
+    pub trait ErrorPayload<'a> {
+        fn report(&self) -> PayloadReport<'a>;
+    }
+
+    impl<'a, T: Error> ErrorPayload<'a> for Payload<'a, T> {
+        fn report(&self) -> PayloadReport<'a> {
+            PayloadReport::new(self.0, Some(self.0))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub trait BoxedErrorPayload<'a> {
+        fn report(&self) -> PayloadReport<'a>;
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<'a, B: super::BoxedError> BoxedErrorPayload<'a> for Payload<'a, B> {
+        fn report(&self) -> PayloadReport<'a> {
+            PayloadReport::new(self.0, Some(self.0.as_dyn_error()))
+        }
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    pub trait BoxedErrorPayload<'a> {}
+
+    pub trait DebugPayload<'a> {
+        fn report(&self) -> PayloadReport<'a>;
+    }
+
+    impl<'a, T: Debug> DebugPayload<'a> for &Payload<'a, T> {
+        fn report(&self) -> PayloadReport<'a> {
+            PayloadReport::new(self.0, None)
+        }
+    }
+}
+
+/// Creates a [`PayloadReport`] for a reference to a payload.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __payload {
+    ($payload:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::testing::__private::{BoxedErrorPayload as _, DebugPayload as _, ErrorPayload as _};
+        (&$crate::testing::__private::Payload($payload)).report()
+    }};
+}
@@ -0,0 +1,127 @@
+use std::fmt::{Display, Formatter};
+
+use fluent_result::bool::{ArrayChecks, Checks, Then};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Invalid {
+    Name,
+    Age,
+    Email,
+}
+
+impl Display for Invalid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Name => write!(f, "invalid name"),
+            Self::Age => write!(f, "invalid age"),
+            Self::Email => write!(f, "invalid email"),
+        }
+    }
+}
+
+impl std::error::Error for Invalid {}
+
+mod checks {
+    use super::*;
+
+    #[test]
+    fn finish_ok() {
+        let mut checks = Checks::new();
+        checks
+            .then_err(false, Invalid::Name)
+            .then_err_with(false, || Invalid::Age)
+            .check(false.then_err(Invalid::Email));
+        assert!(checks.errors().is_empty());
+        assert_eq!(checks.finish(), Ok(()));
+    }
+
+    #[test]
+    fn finish_err() {
+        let mut checks = Checks::new();
+        checks.then_err(true, Invalid::Name).then_err_with(true, || Invalid::Age).check(true.then_err(Invalid::Email));
+        assert_eq!(checks.errors(), [Invalid::Name, Invalid::Age, Invalid::Email]);
+
+        let errors = checks.finish().expect_err("should fail");
+        assert_eq!(errors.len(), 3);
+        assert!(!errors.is_empty());
+        assert_eq!(errors.iter().copied().collect::<Vec<_>>(), [Invalid::Name, Invalid::Age, Invalid::Email]);
+        assert_eq!(Vec::from(errors), [Invalid::Name, Invalid::Age, Invalid::Email]);
+    }
+
+    #[test]
+    fn then_err_with_lazy() {
+        let mut checks: Checks<Invalid> = Checks::default();
+        checks.then_err_with(false, || panic!("should not be called"));
+        assert_eq!(checks.finish(), Ok(()));
+    }
+
+    #[test]
+    fn display() {
+        let mut checks = Checks::new();
+        checks.then_err(true, Invalid::Name);
+        let errors = checks.finish().expect_err("should fail");
+        assert_eq!(errors.to_string(), "1 error:\n    0: invalid name");
+
+        let mut checks = Checks::new();
+        checks.then_err(true, Invalid::Name).then_err(true, Invalid::Email);
+        let errors = checks.finish().expect_err("should fail");
+        assert_eq!(errors.to_string(), "2 errors:\n    0: invalid name\n    1: invalid email");
+    }
+
+    #[test]
+    fn into_error() {
+        let mut checks = Checks::new();
+        checks.then_err(true, Invalid::Age);
+        let error: Box<dyn std::error::Error> = checks.finish().expect_err("should fail").into();
+        assert_eq!(error.to_string(), "1 error:\n    0: invalid age");
+    }
+}
+
+mod array_checks {
+    use super::*;
+
+    #[test]
+    fn finish_ok() {
+        let mut checks = ArrayChecks::<Invalid, 2>::new();
+        checks
+            .then_err(false, Invalid::Name)
+            .then_err_with(false, || Invalid::Age)
+            .check(false.then_err(Invalid::Email));
+        assert!(checks.errors().is_empty());
+        assert_eq!(checks.finish(), Ok(()));
+    }
+
+    #[test]
+    fn finish_err() {
+        let mut checks = ArrayChecks::<Invalid, 3>::new();
+        checks.then_err(true, Invalid::Name).then_err_with(true, || Invalid::Age).check(true.then_err(Invalid::Email));
+
+        let errors = checks.finish().expect_err("should fail");
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors.overflowed(), 0);
+        assert_eq!(errors.iter().copied().collect::<Vec<_>>(), [Invalid::Name, Invalid::Age, Invalid::Email]);
+        assert_eq!(errors.into_iter().collect::<Vec<_>>(), [Invalid::Name, Invalid::Age, Invalid::Email]);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut checks = ArrayChecks::<Invalid, 1>::default();
+        checks.then_err(true, Invalid::Name).then_err(true, Invalid::Age).then_err(true, Invalid::Email);
+
+        let errors = checks.finish().expect_err("should fail");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors.overflowed(), 2);
+        assert_eq!(errors.total(), 3);
+        assert_eq!(errors.to_string(), "3 errors:\n    0: invalid name\n    ... and 2 more");
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut checks = ArrayChecks::<Invalid, 0>::new();
+        checks.then_err(true, Invalid::Name);
+
+        let errors = checks.finish().expect_err("should fail");
+        assert!(errors.is_empty());
+        assert_eq!(errors.to_string(), "1 error:\n    ... and 1 more");
+    }
+}
@@ -0,0 +1,150 @@
+use fluent_result::bool::{bail, ensure, ensure_eq, ensure_not, ensure_some};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Invalid(&'static str);
+
+#[derive(Debug, PartialEq, Eq)]
+enum AppError {
+    Invalid(Invalid),
+}
+
+impl From<Invalid> for AppError {
+    fn from(err: Invalid) -> Self {
+        Self::Invalid(err)
+    }
+}
+
+fn check_ensure(value: u32) -> Result<u32, Invalid> {
+    ensure!(value > 0, Invalid("zero"));
+    Ok(value)
+}
+
+fn check_ensure_not(value: u32) -> Result<u32, Invalid> {
+    ensure_not!(value > 10, Invalid("too large"));
+    Ok(value)
+}
+
+fn check_bail(value: u32) -> Result<u32, Invalid> {
+    if value == 0 {
+        bail!(Invalid("zero"));
+    }
+    Ok(value)
+}
+
+fn check_ensure_eq(value: u32) -> Result<u32, Invalid> {
+    ensure_eq!(value, 1, Invalid("not one"));
+    Ok(value)
+}
+
+fn check_ensure_some(value: Option<u32>) -> Result<u32, Invalid> {
+    let value = ensure_some!(value, Invalid("missing"));
+    Ok(value)
+}
+
+fn check_from(value: u32) -> Result<u32, AppError> {
+    ensure!(value > 0, Invalid("zero"));
+    Ok(value)
+}
+
+fn check_format(value: u32) -> Result<u32, String> {
+    ensure!(value > 0, "{} is zero", value);
+    ensure_not!(value > 10, "{value} is larger than {}", 10);
+    ensure_eq!(value % 2, 1, "{} is even", value);
+    ensure_some!(value.checked_mul(u32::MAX / 8), "{} overflows", value);
+    if value == 5 {
+        bail!("{} is not allowed", value);
+    }
+    Ok(value)
+}
+
+fn check_literal(value: u32) -> Result<u32, &'static str> {
+    ensure!(value > 0, "zero",);
+    Ok(value)
+}
+
+fn check_status(value: u32) -> Result<u32, i32> {
+    ensure!(value > 0, 404);
+    ensure_not!(value > 10, 413);
+    ensure_eq!(value % 2, 1, 422);
+    ensure_some!(value.checked_mul(u32::MAX / 8), 500,);
+    if value == 5 {
+        bail!(403);
+    }
+    Ok(value)
+}
+
+fn check_char(value: char) -> Result<char, char> {
+    ensure!(value.is_ascii(), '?');
+    if value == 'x' {
+        bail!('x');
+    }
+    Ok(value)
+}
+
+#[test]
+fn ensure() {
+    assert_eq!(check_ensure(1), Ok(1));
+    assert_eq!(check_ensure(0), Err(Invalid("zero")));
+}
+
+#[test]
+fn ensure_not() {
+    assert_eq!(check_ensure_not(1), Ok(1));
+    assert_eq!(check_ensure_not(11), Err(Invalid("too large")));
+}
+
+#[test]
+fn bail() {
+    assert_eq!(check_bail(1), Ok(1));
+    assert_eq!(check_bail(0), Err(Invalid("zero")));
+}
+
+#[test]
+fn ensure_eq() {
+    assert_eq!(check_ensure_eq(1), Ok(1));
+    assert_eq!(check_ensure_eq(2), Err(Invalid("not one")));
+}
+
+#[test]
+fn ensure_some() {
+    assert_eq!(check_ensure_some(Some(1)), Ok(1));
+    assert_eq!(check_ensure_some(None), Err(Invalid("missing")));
+}
+
+#[test]
+fn ensure_converts_error() {
+    assert_eq!(check_from(0), Err(AppError::Invalid(Invalid("zero"))));
+}
+
+#[test]
+fn format_form() {
+    assert_eq!(check_format(1), Ok(1));
+    assert_eq!(check_format(0), Err("0 is zero".to_string()));
+    assert_eq!(check_format(11), Err("11 is larger than 10".to_string()));
+    assert_eq!(check_format(2), Err("2 is even".to_string()));
+    assert_eq!(check_format(9), Err("9 overflows".to_string()));
+    assert_eq!(check_format(5), Err("5 is not allowed".to_string()));
+}
+
+#[test]
+fn literal_form() {
+    assert_eq!(check_literal(1), Ok(1));
+    assert_eq!(check_literal(0), Err("zero"));
+}
+
+#[test]
+fn integer_literal_form() {
+    assert_eq!(check_status(1), Ok(1));
+    assert_eq!(check_status(0), Err(404));
+    assert_eq!(check_status(11), Err(413));
+    assert_eq!(check_status(2), Err(422));
+    assert_eq!(check_status(9), Err(500));
+    assert_eq!(check_status(5), Err(403));
+}
+
+#[test]
+fn char_literal_form() {
+    assert_eq!(check_char('a'), Ok('a'));
+    assert_eq!(check_char('é'), Err('?'));
+    assert_eq!(check_char('x'), Err('x'));
+}
@@ -52,3 +52,47 @@ mod rls_expect {
     test_panic!(rls, assert_false_panic, true, assert_false, "assertion failed: expected `false` but was `true`");
     test_panic!(rls, expect_false_panic, true, expect_false, "false", "false");
 }
+
+// Tests for ExpectIn with explicit modes
+mod expect_in {
+    use fluent_result::bool::ExpectIn;
+    use fluent_result::expect::mode::{Debug, Mode, Release};
+
+    struct Never;
+
+    impl Mode for Never {
+        const ENABLED: bool = false;
+    }
+
+    #[test]
+    fn assert_true_in() {
+        true.assert_true_in::<Release>();
+        true.assert_true_in::<Debug>();
+    }
+
+    #[test]
+    fn expect_false_in() {
+        false.expect_false_in::<Release>("false");
+        false.expect_false_in::<Debug>("false");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: expected `true` but was `false`")]
+    fn assert_true_in_release_panic() {
+        false.assert_true_in::<Release>();
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "false"))]
+    fn expect_false_in_debug_panic() {
+        true.expect_false_in::<Debug>("false");
+    }
+
+    #[test]
+    fn custom_mode_disabled() {
+        false.assert_true_in::<Never>();
+        false.expect_true_in::<Never>("true");
+        true.assert_false_in::<Never>();
+        true.expect_false_in::<Never>("false");
+    }
+}
@@ -0,0 +1,60 @@
+use fluent_result::bool::{GuardIter, all_or_err, all_or_err_with, any_or_err};
+
+#[test]
+fn all_or_err_first_failure() {
+    assert_eq!(all_or_err([(true, 0), (false, 1), (false, 2)]), Err(1));
+    assert_eq!(all_or_err([(true, 0), (true, 1)]), Ok(()));
+    assert_eq!(all_or_err(Vec::<(bool, u8)>::new()), Ok(()));
+}
+
+#[test]
+fn all_or_err_with_lazy() {
+    let conditions: [(bool, &dyn Fn() -> u8); 3] =
+        [(true, &|| panic!("should not be called")), (false, &|| 1), (false, &|| 2)];
+    assert_eq!(all_or_err_with(conditions), Err(1));
+    assert_eq!(all_or_err_with(std::iter::empty::<(bool, fn() -> u8)>()), Ok(()));
+}
+
+#[test]
+fn all_or_err_with_capturing() {
+    let (name, age) = ("alice", 16);
+    let conditions: [(bool, &dyn Fn() -> String); 2] =
+        [(!name.is_empty(), &|| "name is empty".to_string()), (age >= 18, &|| format!("{name} is {age}"))];
+    assert_eq!(all_or_err_with(conditions), Err("alice is 16".to_string()));
+}
+
+#[test]
+fn all_or_err_with_fn_once() {
+    let names = vec![String::from("alice"), String::new()];
+    let result =
+        all_or_err_with(names.into_iter().enumerate().map(|(index, name)| (!name.is_empty(), move || (index, name))));
+    assert_eq!(result, Err((1, String::new())));
+}
+
+#[test]
+fn any_or_err_conditions() {
+    assert_eq!(any_or_err([false, true], "error"), Ok(()));
+    assert_eq!(any_or_err([false, false], "error"), Err("error"));
+    assert_eq!(any_or_err([], "error"), Err("error"));
+}
+
+#[test]
+fn all_or_err_at() {
+    assert_eq!([true, false, false].into_iter().all_or_err_at(|index| index), Err(1));
+    assert_eq!([true, true].into_iter().all_or_err_at(|index| index), Ok(()));
+}
+
+#[test]
+fn all_or_err_at_short_circuits() {
+    let mut inspected = 0;
+    let result = [true, false, true].into_iter().inspect(|_| inspected += 1).all_or_err_at(|index| index);
+    assert_eq!(result, Err(1));
+    assert_eq!(inspected, 2);
+}
+
+#[test]
+fn position_or_err() {
+    assert_eq!([false, true, true].into_iter().position_or_err("error"), Ok(1));
+    assert_eq!([false, false].into_iter().position_or_err("error"), Err("error"));
+    assert_eq!(std::iter::empty().position_or_err("error"), Err("error"));
+}
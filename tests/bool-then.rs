@@ -12,6 +12,14 @@ fn then_err_with() {
     assert_eq!(false.then_err_with(|| "error"), Ok(()));
 }
 
+#[test]
+fn then_msg_err() {
+    use fluent_result::error::MessageError;
+
+    assert_eq!(true.then_msg_err("error"), Err(MessageError("error")));
+    assert_eq!(false.then_msg_err("error"), Ok(()));
+}
+
 #[test]
 fn to_result() {
     assert_eq!(true.to_result(42, 0), Ok(42));
@@ -24,8 +32,149 @@ fn to_result_with() {
     assert_eq!(false.to_result_with(|| 42, || 0), Err(0));
 }
 
+#[test]
+fn then_break() {
+    use std::ops::ControlFlow;
+
+    assert_eq!(true.then_break(42), ControlFlow::Break(42));
+    assert_eq!(false.then_break(42), ControlFlow::Continue(()));
+}
+
+#[test]
+fn then_break_with() {
+    use std::ops::ControlFlow;
+
+    assert_eq!(true.then_break_with(|| 42), ControlFlow::Break(42));
+    assert_eq!(false.then_break_with(|| -> u32 { panic!("should not be called") }), ControlFlow::Continue(()));
+}
+
 #[test]
 fn then_none() {
     assert_eq!(true.then_none(), None);
     assert_eq!(false.then_none(), Some(()));
 }
+
+mod then_result {
+    use fluent_result::bool::ThenResult;
+    use fluent_result::nested::NestedError;
+
+    const TRUE: Result<bool, &str> = Ok(true);
+    const FALSE: Result<bool, &str> = Ok(false);
+    const FAILED: Result<bool, &str> = Err("failed");
+
+    #[test]
+    fn then_err() {
+        assert_eq!(TRUE.then_err("error"), Err(NestedError::Inner("error")));
+        assert_eq!(FALSE.then_err("error"), Ok(()));
+        assert_eq!(FAILED.then_err("error"), Err(NestedError::Outer("failed")));
+    }
+
+    #[test]
+    fn then_err_with() {
+        assert_eq!(TRUE.then_err_with(|| "error"), Err(NestedError::Inner("error")));
+        assert_eq!(FALSE.then_err_with(|| "error"), Ok(()));
+        assert_eq!(FAILED.then_err_with(|| "error"), Err(NestedError::Outer("failed")));
+    }
+
+    #[test]
+    fn to_result() {
+        assert_eq!(TRUE.to_result(42, 0), Ok(42));
+        assert_eq!(FALSE.to_result(42, 0), Err(NestedError::Inner(0)));
+        assert_eq!(FAILED.to_result(42, 0), Err(NestedError::Outer("failed")));
+    }
+
+    #[test]
+    fn to_result_with() {
+        assert_eq!(TRUE.to_result_with(|| 42, || 0), Ok(42));
+        assert_eq!(FALSE.to_result_with(|| 42, || 0), Err(NestedError::Inner(0)));
+        assert_eq!(FAILED.to_result_with(|| 42, || 0), Err(NestedError::Outer("failed")));
+    }
+
+    #[test]
+    fn then_none() {
+        assert_eq!(TRUE.then_none(), Ok(None));
+        assert_eq!(FALSE.then_none(), Ok(Some(())));
+        assert_eq!(FAILED.then_none(), Err("failed"));
+    }
+}
+
+mod then_option {
+    use fluent_result::bool::ThenOption;
+
+    #[test]
+    fn then_err() {
+        assert_eq!(Some(true).then_err(false, "error"), Err("error"));
+        assert_eq!(Some(false).then_err(true, "error"), Ok(()));
+        assert_eq!(None.then_err(true, "error"), Err("error"));
+        assert_eq!(None.then_err(false, "error"), Ok(()));
+    }
+
+    #[test]
+    fn then_err_with() {
+        assert_eq!(Some(true).then_err_with(false, || "error"), Err("error"));
+        assert_eq!(None.then_err_with(false, || "error"), Ok(()));
+    }
+
+    #[test]
+    fn to_result() {
+        assert_eq!(Some(true).to_result(false, 42, 0), Ok(42));
+        assert_eq!(Some(false).to_result(true, 42, 0), Err(0));
+        assert_eq!(None.to_result(true, 42, 0), Ok(42));
+        assert_eq!(None.to_result(false, 42, 0), Err(0));
+    }
+
+    #[test]
+    fn to_result_with() {
+        assert_eq!(Some(false).to_result_with(true, || 42, || 0), Err(0));
+        assert_eq!(None.to_result_with(true, || 42, || 0), Ok(42));
+    }
+
+    #[test]
+    fn then_none() {
+        assert_eq!(Some(true).then_none(false), None);
+        assert_eq!(Some(false).then_none(true), Some(()));
+        assert_eq!(None.then_none(true), None);
+        assert_eq!(None.then_none(false), Some(()));
+    }
+}
+
+mod ensure_ordering {
+    use std::cmp::Ordering::{Equal, Greater, Less};
+
+    use fluent_result::bool::EnsureOrdering;
+
+    #[test]
+    fn ensure_less() {
+        assert_eq!(Less.ensure_less("error"), Ok(()));
+        assert_eq!(Equal.ensure_less("error"), Err("error"));
+        assert_eq!(Greater.ensure_less("error"), Err("error"));
+    }
+
+    #[test]
+    fn ensure_less_or_equal() {
+        assert_eq!(Less.ensure_less_or_equal("error"), Ok(()));
+        assert_eq!(Equal.ensure_less_or_equal("error"), Ok(()));
+        assert_eq!(Greater.ensure_less_or_equal("error"), Err("error"));
+    }
+
+    #[test]
+    fn ensure_equal() {
+        assert_eq!(Less.ensure_equal("error"), Err("error"));
+        assert_eq!(Equal.ensure_equal("error"), Ok(()));
+        assert_eq!(Greater.ensure_equal("error"), Err("error"));
+    }
+
+    #[test]
+    fn ensure_greater_or_equal() {
+        assert_eq!(Less.ensure_greater_or_equal("error"), Err("error"));
+        assert_eq!(Equal.ensure_greater_or_equal("error"), Ok(()));
+        assert_eq!(Greater.ensure_greater_or_equal("error"), Ok(()));
+    }
+
+    #[test]
+    fn ensure_greater() {
+        assert_eq!(Less.ensure_greater("error"), Err("error"));
+        assert_eq!(Equal.ensure_greater("error"), Err("error"));
+        assert_eq!(Greater.ensure_greater("error"), Ok(()));
+    }
+}
@@ -0,0 +1,109 @@
+use std::error::Error;
+use std::num::ParseIntError;
+
+use fluent_result::error::MultiError;
+use fluent_result::expect::ErrorReport;
+use fluent_result::into::CollectAll;
+use fluent_result::nested::BoxErr;
+
+fn parse(inputs: &[&str]) -> impl Iterator<Item = Result<u32, ParseIntError>> {
+    inputs.iter().map(|input| input.parse())
+}
+
+#[test]
+fn collect_all_ok() {
+    assert_eq!(parse(&["1", "2", "3"]).collect_all::<Vec<_>>(), Ok(vec![1, 2, 3]));
+    assert_eq!(parse(&[]).collect_all::<Vec<_>>(), Ok(vec![]));
+}
+
+#[test]
+fn collect_all_err() {
+    let errors = parse(&["1", "x", "3", ""]).collect_all::<Vec<_>>().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].to_string(), "invalid digit found in string");
+    assert_eq!(errors[1].to_string(), "cannot parse integer from empty string");
+}
+
+#[test]
+fn collect_all_into_other_collection() {
+    let set = [Ok::<_, &str>(1), Ok(2)].into_iter().collect_all::<std::collections::BTreeSet<_>>();
+    assert_eq!(set, Ok([1, 2].into()));
+}
+
+#[test]
+fn partition_results() {
+    let (values, errors) = parse(&["1", "x", "3"]).partition_results();
+    assert_eq!(values, [1, 3]);
+    assert_eq!(errors.len(), 1);
+
+    let (values, errors) = [Ok::<u32, &str>(1)].into_iter().partition_results();
+    assert_eq!(values, [1]);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn collect_errs_limit() {
+    let results = [Err(1), Ok(()), Err(2), Err(3)];
+    assert_eq!(results.into_iter().collect_errs_limit::<Vec<_>>(2), Err(vec![1, 2]));
+    assert_eq!(results.into_iter().collect_errs_limit::<Vec<_>>(5), Err(vec![1, 2, 3]));
+    assert_eq!([Ok::<_, ()>(1)].into_iter().collect_errs_limit::<Vec<_>>(1), Ok(vec![1]));
+}
+
+#[test]
+fn collect_errs_limit_zero() {
+    let mut seen = 0;
+    let results = [Ok(()), Err(1), Err(2)].into_iter().inspect(|_| seen += 1);
+    assert_eq!(results.collect_errs_limit::<Vec<_>>(0), Err(vec![1]));
+    assert_eq!(seen, 2);
+    assert_eq!([Ok::<_, ()>(1)].into_iter().collect_errs_limit::<Vec<_>>(0), Ok(vec![1]));
+}
+
+#[test]
+fn collect_errs_limit_stops_early() {
+    let mut seen = 0;
+    let results = [Err(1), Err(2), Ok(()), Err(3)].into_iter().inspect(|_| seen += 1);
+    assert_eq!(results.collect_errs_limit::<Vec<_>>(2), Err(vec![1, 2]));
+    assert_eq!(seen, 2);
+}
+
+#[test]
+fn multi_error_display() {
+    let error: MultiError<ParseIntError> = parse(&["x"]).collect_all::<Vec<_>>().unwrap_err().into();
+    assert_eq!(error.to_string(), "1 error:\n    0: invalid digit found in string");
+
+    let error = MultiError::new(parse(&["x", ""]).partition_results().1);
+    assert_eq!(
+        error.to_string(),
+        "2 errors:\n    0: invalid digit found in string\n    1: cannot parse integer from empty string"
+    );
+}
+
+#[test]
+fn multi_error_report() {
+    let error: MultiError<_> = parse(&["x"]).filter_map(Result::err).collect();
+    assert_eq!(ErrorReport::new(&error).to_string(), "1 error:\n    0: invalid digit found in string");
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn multi_error_box_err() {
+    fn parse_all(inputs: &[&str]) -> Result<Vec<u32>, MultiError<ParseIntError>> {
+        Ok(parse(inputs).collect_all()?)
+    }
+
+    let boxed: Result<Vec<u32>, Box<dyn Error>> = parse_all(&["1", "x"]).box_err();
+    let error = boxed.unwrap_err();
+    assert!(error.is::<MultiError<ParseIntError>>());
+    assert_eq!(error.to_string(), "1 error:\n    0: invalid digit found in string");
+}
+
+#[test]
+fn multi_error_iter() {
+    let error = MultiError::new(vec!["first", "second"]);
+    assert!(!error.is_empty());
+    assert_eq!(error.errors(), ["first", "second"]);
+    assert_eq!(error.iter().copied().collect::<Vec<_>>(), ["first", "second"]);
+    assert_eq!((&error).into_iter().count(), 2);
+    assert_eq!(Vec::from(error.clone()), ["first", "second"]);
+    assert_eq!(error.into_iter().collect::<Vec<_>>(), ["first", "second"]);
+}
@@ -0,0 +1,79 @@
+use std::error::Error;
+
+use fluent_result::error::define_error;
+use fluent_result::nested::{BoxErr, FlattenErr, NestedError};
+
+define_error! {
+    /// A unit error.
+    pub struct NotFound => "not found";
+
+    struct ReadFailed(std::fmt::Error) => "read failed";
+
+    /// An enum error.
+    #[allow(dead_code)]
+    pub enum AppError {
+        /// A unit variant.
+        Unknown => "unknown error",
+        Missing(NotFound) => "resource missing",
+        Read(ReadFailed) => "read error",
+    }
+
+    enum Single {
+        Only(NotFound) => "single",
+    }
+}
+
+#[test]
+fn unit_struct() {
+    assert_eq!(NotFound.to_string(), "not found");
+    assert!(NotFound.source().is_none());
+    assert_eq!(format!("{NotFound:?}"), "NotFound");
+}
+
+#[test]
+fn source_struct() {
+    let err = ReadFailed::from(std::fmt::Error);
+    assert_eq!(err.to_string(), "read failed");
+    assert_eq!(err.source().map(ToString::to_string), Some(std::fmt::Error.to_string()));
+}
+
+#[test]
+fn enum_display_and_source() {
+    assert_eq!(AppError::Unknown.to_string(), "unknown error");
+    assert!(AppError::Unknown.source().is_none());
+
+    let err = AppError::from(NotFound);
+    assert_eq!(err.to_string(), "resource missing");
+    assert_eq!(err.source().map(ToString::to_string), Some("not found".to_string()));
+
+    let err = AppError::from(ReadFailed(std::fmt::Error));
+    assert_eq!(err.to_string(), "read error");
+    assert_eq!(err.source().and_then(Error::source).map(ToString::to_string), Some(std::fmt::Error.to_string()));
+}
+
+#[test]
+fn single_variant_enum() {
+    let Single::Only(inner) = Single::from(NotFound);
+    assert_eq!(inner, NotFound);
+    assert_eq!(Single::Only(NotFound).source().map(ToString::to_string), Some("not found".to_string()));
+}
+
+#[test]
+fn question_mark_conversion() {
+    fn find() -> Result<(), AppError> {
+        Err(NotFound)?
+    }
+
+    assert!(matches!(find(), Err(AppError::Missing(NotFound))));
+}
+
+#[test]
+fn nested() {
+    let result: Result<Result<u32, NotFound>, AppError> = Ok(Err(NotFound));
+    let err: NestedError<NotFound, AppError> = result.flatten_err().unwrap_err();
+    assert_eq!(err.source().map(ToString::to_string), Some("not found".to_string()));
+
+    let result: Result<Result<u32, NotFound>, AppError> = Err(AppError::Unknown);
+    let boxed: Result<u32, Box<dyn Error>> = result.box_err();
+    assert_eq!(boxed.unwrap_err().to_string(), "unknown error");
+}
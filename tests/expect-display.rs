@@ -0,0 +1,76 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use fluent_result::expect::ExpectDisplay;
+use fluent_result::nested::{BoxErr, FlattenErr, NestedError};
+
+#[derive(Debug)]
+struct Root;
+
+impl Display for Root {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "root cause")
+    }
+}
+
+impl Error for Root {}
+
+type Nested = NestedError<NestedError<Root, std::fmt::Error>, std::fmt::Error>;
+
+fn nested() -> Nested {
+    NestedError::Inner(NestedError::Inner(Root))
+}
+
+#[test]
+fn expect_display_ok() {
+    let result: Result<u32, Root> = Ok(42);
+    assert_eq!(result.expect_display("should be ok"), 42);
+}
+
+#[test]
+fn unwrap_display_ok() {
+    let result: Result<u32, Root> = Ok(42);
+    assert_eq!(result.unwrap_display(), 42);
+}
+
+#[test]
+#[should_panic(expected = "loading failed: root cause")]
+fn expect_display_err() {
+    let result: Result<u32, Root> = Err(Root);
+    result.expect_display("loading failed");
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap_display()` on an `Err` value: root cause")]
+fn unwrap_display_err() {
+    let result: Result<u32, Root> = Err(Root);
+    result.unwrap_display();
+}
+
+#[test]
+#[should_panic(expected = "loading failed: root cause\n\nCaused by:\n    0: root cause\n    1: root cause")]
+fn expect_display_chain() {
+    let result: Result<u32, Nested> = Err(nested());
+    result.expect_display("loading failed");
+}
+
+#[test]
+#[should_panic(expected = "loading failed: root cause\n\nCaused by:\n    0: root cause")]
+fn expect_display_box_err() {
+    let result: Result<Result<u32, Root>, std::fmt::Error> = Ok(Err(Root));
+    result.flatten_err().box_err().expect_display("loading failed");
+}
+
+#[test]
+#[should_panic(expected = "called `Result::unwrap_display()` on an `Err` value: root cause")]
+fn unwrap_display_box_err() {
+    let result: Result<u32, Root> = Err(Root);
+    result.box_err().unwrap_display();
+}
+
+#[test]
+#[should_panic(expected = "loading failed: root cause")]
+fn expect_display_box_send_sync() {
+    let result: Result<u32, Box<dyn Error + Send + Sync>> = Err(Box::new(Root));
+    result.expect_display("loading failed");
+}
@@ -0,0 +1,184 @@
+macro_rules! test_no_panic {
+    ($test_name:ident, $value:expr, $method:ident $(, $arg:expr)*) => {
+        #[test]
+        fn $test_name() {
+            $value.$method($($arg),*);
+        }
+    };
+}
+
+macro_rules! test_panic {
+    (dbg, $test_name:ident, $value:expr, $method:ident, $expected:expr $(, $arg:expr)*) => {
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic(expected = $expected))]
+        fn $test_name() {
+            $value.$method($($arg),*);
+        }
+    };
+    (rls, $test_name:ident, $value:expr, $method:ident, $expected:expr $(, $arg:expr)*) => {
+        #[test]
+        #[should_panic(expected = $expected)]
+        fn $test_name() {
+            $value.$method($($arg),*);
+        }
+    };
+}
+
+mod dbg_expect_items {
+    use fluent_result::expect::dbg::ExpectItems;
+
+    test_no_panic!(assert_empty, Vec::<u8>::new(), assert_empty);
+    test_no_panic!(expect_empty, Vec::<u8>::new(), expect_empty, "test");
+    test_no_panic!(assert_at_most, vec![1, 2], assert_at_most, 2);
+    test_no_panic!(expect_at_most, vec![1, 2], expect_at_most, 2, "test");
+
+    test_panic!(
+        dbg,
+        assert_empty_panic,
+        vec![1, 2],
+        assert_empty,
+        "assertion failed: expected at most 0 items but found at least 2"
+    );
+    test_panic!(dbg, expect_empty_panic, vec![1], expect_empty, "test", "test");
+    test_panic!(
+        dbg,
+        assert_at_most_panic,
+        vec![1, 2, 3],
+        assert_at_most,
+        "expected at most 2 items but found at least 3",
+        2
+    );
+    test_panic!(dbg, expect_at_most_panic, vec![1, 2, 3], expect_at_most, "test", 2, "test");
+    test_panic!(dbg, assert_at_most_infinite_panic, 0.., assert_at_most, "expected at most 2 items", 2);
+    test_panic!(dbg, assert_single_infinite_panic, 0.., assert_single, "expected at most 1 item");
+    test_no_panic!(assert_unique, [1, 2, 3].iter(), assert_unique);
+    test_no_panic!(expect_unique, vec![1, 2, 3], expect_unique, "test");
+    test_panic!(
+        dbg,
+        assert_unique_panic,
+        [1, 2, 1].iter(),
+        assert_unique,
+        "assertion failed: expected unique items but item 2 equals item 0"
+    );
+    test_panic!(dbg, expect_unique_panic, vec![1, 1], expect_unique, "test", "test");
+    test_panic!(
+        dbg,
+        assert_single_many_panic,
+        vec![1, 2],
+        assert_single,
+        "expected at most 1 item but found at least 2"
+    );
+
+    #[test]
+    fn expect_single() {
+        assert_eq!(vec![1].expect_single("test"), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: expected at least 1 item but found 0")]
+    fn assert_single_empty_panic() {
+        Vec::<u8>::new().assert_single();
+    }
+}
+
+mod rls_expect_items {
+    use fluent_result::expect::rls::ExpectItems;
+
+    test_no_panic!(assert_empty, Vec::<u8>::new(), assert_empty);
+    test_no_panic!(expect_empty, Vec::<u8>::new(), expect_empty, "test");
+    test_no_panic!(assert_at_most, vec![1, 2], assert_at_most, 2);
+    test_no_panic!(expect_at_most, vec![1, 2], expect_at_most, 2, "test");
+
+    test_panic!(
+        rls,
+        assert_empty_panic,
+        vec![1, 2],
+        assert_empty,
+        "assertion failed: expected at most 0 items but found at least 2"
+    );
+    test_panic!(rls, expect_empty_panic, vec![1], expect_empty, "test", "test");
+    test_panic!(
+        rls,
+        assert_at_most_panic,
+        vec![1, 2, 3],
+        assert_at_most,
+        "expected at most 2 items but found at least 3",
+        2
+    );
+    test_panic!(rls, expect_at_most_panic, vec![1, 2, 3], expect_at_most, "test", 2, "test");
+    test_panic!(rls, assert_at_most_infinite_panic, 0.., assert_at_most, "expected at most 2 items", 2);
+    test_panic!(rls, assert_single_infinite_panic, 0.., assert_single, "expected at most 1 item");
+    test_no_panic!(assert_unique, [1, 2, 3].iter(), assert_unique);
+    test_no_panic!(expect_unique, vec![1, 2, 3], expect_unique, "test");
+    test_panic!(
+        rls,
+        assert_unique_panic,
+        [1, 2, 1].iter(),
+        assert_unique,
+        "assertion failed: expected unique items but item 2 equals item 0"
+    );
+    test_panic!(rls, expect_unique_panic, vec![1, 1], expect_unique, "test", "test");
+    test_panic!(
+        rls,
+        assert_single_many_panic,
+        vec![1, 2],
+        assert_single,
+        "expected at most 1 item but found at least 2"
+    );
+    test_panic!(rls, expect_single_empty_panic, Vec::<u8>::new(), expect_single, "test", "test");
+
+    #[test]
+    fn assert_single() {
+        assert_eq!([1].iter().assert_single(), &1);
+    }
+}
+
+mod try_items {
+    use fluent_result::expect::{CountError, DuplicateError, TryItems};
+
+    #[test]
+    fn try_empty() {
+        assert_eq!(Vec::<u8>::new().try_empty(), Ok(()));
+        assert_eq!(vec![1, 2].try_empty(), Err(CountError::TooMany { max: 0, at_least: 2 }));
+        assert_eq!((0..).try_empty(), Err(CountError::TooMany { max: 0, at_least: usize::MAX }));
+    }
+
+    #[test]
+    fn try_single() {
+        assert_eq!(vec![1].try_single(), Ok(1));
+        assert_eq!(Vec::<u8>::new().try_single(), Err(CountError::TooFew { min: 1, actual: 0 }));
+        assert_eq!(vec![1, 2, 3].try_single(), Err(CountError::TooMany { max: 1, at_least: 3 }));
+        assert_eq!([1, 2, 3].iter().filter(|n| **n > 1).try_single(), Err(CountError::TooMany { max: 1, at_least: 2 }));
+        assert_eq!((0..).try_single(), Err(CountError::TooMany { max: 1, at_least: usize::MAX }));
+    }
+
+    #[test]
+    fn try_at_most() {
+        assert_eq!(vec![1, 2].try_at_most(2), Ok(()));
+        assert_eq!(vec![1, 2, 3].try_at_most(2), Err(CountError::TooMany { max: 2, at_least: 3 }));
+        assert_eq!(vec![1, 2, 3, 4].try_at_most(2), Err(CountError::TooMany { max: 2, at_least: 4 }));
+        assert_eq!((0..).filter(|n| n % 2 == 0).try_at_most(5), Err(CountError::TooMany { max: 5, at_least: 6 }));
+        assert_eq!((0..).take(3).try_at_most(usize::MAX), Ok(()));
+    }
+
+    #[test]
+    fn try_unique() {
+        assert_eq!(Vec::<u8>::new().try_unique(), Ok(()));
+        assert_eq!(vec![1, 2, 3].try_unique(), Ok(()));
+        assert_eq!(vec![1, 2, 3, 2].try_unique(), Err(DuplicateError { first: 1, second: 3 }));
+        assert_eq!(
+            DuplicateError { first: 1, second: 3 }.to_string(),
+            "expected unique items but item 3 equals item 1"
+        );
+    }
+
+    #[test]
+    fn count_error_display() {
+        assert_eq!(CountError::TooFew { min: 1, actual: 0 }.to_string(), "expected at least 1 item but found 0");
+        assert_eq!(
+            CountError::TooMany { max: 2, at_least: 3 }.to_string(),
+            "expected at most 2 items but found at least 3"
+        );
+        assert_eq!(CountError::TooMany { max: 2, at_least: 3 }.actual(), 3);
+    }
+}
@@ -43,3 +43,31 @@ mod rls_expect_none {
     test_panic!(rls, assert_none_panic, Some(()), assert_none, "called `Option::assert_none()` on a `Some` value");
     test_panic!(rls, expect_none_panic, Some(()), expect_none, "test", "test");
 }
+
+mod expect_none_in {
+    use fluent_result::expect::ExpectNoneIn;
+    use fluent_result::expect::mode::{Mode, Release};
+
+    struct Never;
+
+    impl Mode for Never {
+        const ENABLED: bool = false;
+    }
+
+    #[test]
+    fn assert_none_in() {
+        None::<u8>.assert_none_in::<Release>();
+    }
+
+    #[test]
+    #[should_panic(expected = "test")]
+    fn expect_none_in_release_panic() {
+        Some(()).expect_none_in::<Release>("test");
+    }
+
+    #[test]
+    fn custom_mode_disabled() {
+        Some(()).assert_none_in::<Never>();
+        Some(()).expect_none_in::<Never>("test");
+    }
+}
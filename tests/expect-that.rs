@@ -0,0 +1,82 @@
+mod dbg_expect_that {
+    use fluent_result::expect::dbg::ExpectThat;
+
+    #[test]
+    fn pass_through() {
+        assert_eq!(42.assert_that(|n| n % 2 == 0), 42);
+        assert_eq!(42.expect_that(|n| n % 2 == 0, "even"), 42);
+        assert_eq!(42.assert_eq_to(&42), 42);
+        assert_eq!(42.assert_within(0..=100), 42);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "assertion failed: `41` did not satisfy the predicate"))]
+    fn assert_that_panic() {
+        let _ = 41.assert_that(|n| n % 2 == 0);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "assertion failed: expected `42` but was `41`"))]
+    fn assert_eq_to_panic() {
+        let _ = 41.assert_eq_to(&42);
+    }
+}
+
+mod rls_expect_that {
+    use fluent_result::expect::rls::ExpectThat;
+
+    #[test]
+    fn pass_through() {
+        let names: Vec<String> =
+            ["alice", "bob"].iter().map(|name| name.to_string().assert_that(|n| !n.is_empty())).collect();
+        assert_eq!(names, ["alice", "bob"]);
+        assert_eq!(String::from("alice").assert_eq_to("alice"), "alice");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `41` did not satisfy the predicate")]
+    fn assert_that_panic() {
+        let _ = 41.assert_that(|n| n % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "even")]
+    fn expect_that_panic() {
+        let _ = 41.expect_that(|n| n % 2 == 0, "even");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: expected `\"bob\"` but was `\"alice\"`")]
+    fn assert_eq_to_panic() {
+        let _ = String::from("alice").assert_eq_to("bob");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: expected a value in `0..=100` but was `101`")]
+    fn assert_within_panic() {
+        let _ = 101.assert_within(0..=100);
+    }
+}
+
+mod expect_that_in {
+    use fluent_result::expect::ExpectThatIn;
+    use fluent_result::expect::mode::{Mode, Release};
+
+    struct Never;
+
+    impl Mode for Never {
+        const ENABLED: bool = false;
+    }
+
+    #[test]
+    fn assert_within_in() {
+        assert_eq!(5.assert_within_in::<Release, _>(1..10), 5);
+    }
+
+    #[test]
+    fn custom_mode_disabled() {
+        assert_eq!(41.assert_that_in::<Never>(|_| unreachable!()), 41);
+        assert_eq!(41.assert_eq_to_in::<Never, _>(&42), 41);
+        assert_eq!(41.assert_within_in::<Never, _>(0..10), 41);
+    }
+}
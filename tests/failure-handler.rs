@@ -0,0 +1,71 @@
+#![cfg(feature = "std")]
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use fluent_result::bool::rls::Expect;
+use fluent_result::expect::rls::ExpectNone;
+use fluent_result::expect::{FailureKind, reset_failure_handler, set_failure_handler};
+
+static FAILURES: Mutex<Vec<(FailureKind, String, u32)>> = Mutex::new(Vec::new());
+
+// the failure handler is global, so tests using it must not run concurrently
+static SERIAL: Mutex<()> = Mutex::new(());
+
+fn serial() -> MutexGuard<'static, ()> {
+    SERIAL.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn record(should_panic: bool) {
+    FAILURES.lock().unwrap().clear();
+    set_failure_handler(move |failure| {
+        let record = (failure.kind(), failure.to_string(), failure.location().line());
+        FAILURES.lock().unwrap().push(record);
+        should_panic
+    });
+}
+
+#[test]
+fn handler_receives_failures() {
+    let _serial = serial();
+    record(false);
+
+    false.assert_true();
+    let line = line!() - 1;
+    true.expect_false("should be false");
+    Some(()).expect_none("should be none");
+    true.assert_true();
+
+    reset_failure_handler();
+    assert_eq!(
+        *FAILURES.lock().unwrap(),
+        [
+            (FailureKind::ExpectedTrue, "assertion failed: expected `true` but was `false`".to_string(), line),
+            (FailureKind::ExpectedFalse, "should be false".to_string(), line + 2),
+            (FailureKind::ExpectedNone, "should be none".to_string(), line + 3),
+        ]
+    );
+}
+
+#[test]
+fn handler_can_request_panic() {
+    let _serial = serial();
+    record(true);
+
+    let result = std::panic::catch_unwind(|| false.expect_true("should be true"));
+
+    reset_failure_handler();
+    assert_eq!(*result.unwrap_err().downcast::<String>().unwrap(), "should be true");
+    assert_eq!(FAILURES.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn reset_handler_panics() {
+    let _serial = serial();
+    record(false);
+    reset_failure_handler();
+
+    let result = std::panic::catch_unwind(|| Some(1).assert_none());
+
+    assert!(result.is_err());
+    assert!(FAILURES.lock().unwrap().is_empty());
+}
@@ -0,0 +1,68 @@
+use std::ops::Bound;
+
+use fluent_result::guard::{Ensure, Mismatch, OutOfRange};
+
+#[test]
+fn ensure_eq() {
+    assert_eq!("a".ensure_eq(&"a"), Ok("a"));
+    assert_eq!("a".ensure_eq(&"b"), Err(Mismatch { expected: "b", actual: "a" }));
+}
+
+#[test]
+fn ensure_in() {
+    assert_eq!(5.ensure_in(1..10), Ok(5));
+    assert_eq!(
+        10.ensure_in(1..10),
+        Err(OutOfRange { start: Bound::Included(1), end: Bound::Excluded(10), actual: 10 })
+    );
+    assert_eq!(0.ensure_in(1..), Err(OutOfRange { start: Bound::Included(1), end: Bound::Unbounded, actual: 0 }));
+}
+
+#[test]
+fn ensure_in_nan() {
+    assert!(f64::NAN.ensure_in(0.0..=1.0).is_err());
+}
+
+#[test]
+fn ensure_at_most() {
+    assert_eq!(10.ensure_at_most(10), Ok(10));
+    assert_eq!(
+        11.ensure_at_most(10),
+        Err(OutOfRange { start: Bound::Unbounded, end: Bound::Included(10), actual: 11 })
+    );
+    assert!(f64::NAN.ensure_at_most(1.0).is_err());
+}
+
+#[test]
+fn ensure_at_least() {
+    assert_eq!(10.ensure_at_least(10), Ok(10));
+    assert_eq!(9.ensure_at_least(10), Err(OutOfRange { start: Bound::Included(10), end: Bound::Unbounded, actual: 9 }));
+    assert!(f64::NAN.ensure_at_least(1.0).is_err());
+}
+
+#[test]
+fn mismatch_display() {
+    let err = Mismatch { expected: "b", actual: "a" };
+    assert_eq!(err.to_string(), r#"expected `"b"` but was `"a"`"#);
+}
+
+#[test]
+fn out_of_range_display() {
+    let display = |start, end| OutOfRange { start, end, actual: 0 }.to_string();
+    assert_eq!(
+        display(Bound::Included(1), Bound::Excluded(10)),
+        "expected a value at least `1` and less than `10` but was `0`"
+    );
+    assert_eq!(
+        display(Bound::Excluded(1), Bound::Included(10)),
+        "expected a value greater than `1` and at most `10` but was `0`"
+    );
+    assert_eq!(display(Bound::Unbounded, Bound::Excluded(0)), "expected a value less than `0` but was `0`");
+    assert_eq!(display(Bound::Unbounded, Bound::Unbounded), "expected a value but was `0`");
+}
+
+#[test]
+fn into_error() {
+    let err: Box<dyn std::error::Error> = 11.ensure_in(0..=10).unwrap_err().into();
+    assert_eq!(err.to_string(), "expected a value at least `0` and at most `10` but was `11`");
+}
@@ -0,0 +1,59 @@
+use std::borrow::Cow;
+use std::error::Error;
+
+use fluent_result::bool::Then;
+use fluent_result::error::{ErrMsg, MessageError, OkOrMsg};
+use fluent_result::nested::{BoxErr, FlattenErr};
+
+#[test]
+fn display() {
+    assert_eq!(MessageError::new("oops").to_string(), "oops");
+    assert_eq!(MessageError(Cow::<str>::Owned("oops".to_string())).to_string(), "oops");
+    assert_eq!(*MessageError("oops").message(), "oops");
+}
+
+#[test]
+fn from_conversions() {
+    let error: MessageError = "oops".into();
+    assert_eq!(error, MessageError("oops"));
+
+    let error: MessageError<Cow<'static, str>> = "oops".into();
+    assert_eq!(error, MessageError(Cow::Borrowed("oops")));
+
+    let error: MessageError<Cow<'static, str>> = String::from("oops").into();
+    assert_eq!(error, MessageError(Cow::Owned(String::from("oops"))));
+
+    let error: MessageError<Cow<'static, str>> = MessageError("oops").into();
+    assert_eq!(error, MessageError(Cow::Borrowed("oops")));
+}
+
+#[test]
+fn ok_or_msg() {
+    assert_eq!(Some(42).ok_or_msg("missing"), Ok(42));
+    assert_eq!(None::<u32>.ok_or_msg("missing"), Err(MessageError("missing")));
+}
+
+#[test]
+fn err_msg() {
+    assert_eq!(Ok::<u32, &str>(42).err_msg(), Ok(42));
+    assert_eq!(Err::<u32, &str>("oops").err_msg(), Err(MessageError("oops")));
+    assert_eq!(Err::<u32, String>("oops".to_string()).err_msg(), Err(MessageError("oops".to_string())));
+    assert_eq!(
+        Err::<u32, Cow<'static, str>>(Cow::Borrowed("oops")).err_msg(),
+        Err(MessageError(Cow::Borrowed("oops")))
+    );
+}
+
+#[test]
+fn box_err() {
+    let result: Result<Result<u32, MessageError>, std::fmt::Error> = Ok(true.then_msg_err("even").map(|()| 42));
+    let boxed: Result<u32, Box<dyn Error>> = result.box_err();
+    assert_eq!(boxed.unwrap_err().to_string(), "even");
+}
+
+#[test]
+fn nested_error_source() {
+    let result: Result<Result<u32, MessageError>, MessageError> = Ok(Err(MessageError("inner")));
+    let err = result.flatten_err().unwrap_err();
+    assert_eq!(err.source().map(ToString::to_string), Some("inner".to_string()));
+}
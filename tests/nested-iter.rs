@@ -0,0 +1,98 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+
+use fluent_result::nested::{NestedError, NestedIter};
+
+#[derive(Debug, PartialEq, Eq)]
+struct ReadError;
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("read failed")
+    }
+}
+
+impl Error for ReadError {}
+
+fn reads() -> impl DoubleEndedIterator<Item = Result<Result<u32, ParseIntError>, ReadError>> + ExactSizeIterator {
+    [Ok("1"), Ok("x"), Err(ReadError)].into_iter().map(|read| read.map(str::parse))
+}
+
+#[test]
+fn flatten_errs() {
+    let results: Vec<_> = reads().flatten_errs().collect();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0], Ok(1));
+    assert!(matches!(results[1], Err(NestedError::Inner(_))));
+    assert_eq!(results[2], Err(NestedError::Outer(ReadError)));
+}
+
+#[test]
+fn flatten_errs_is_lazy() {
+    let mut seen = 0;
+    let mut results = reads().inspect(|_| seen += 1).flatten_errs();
+    assert_eq!(results.next(), Some(Ok(1)));
+    drop(results);
+    assert_eq!(seen, 1);
+}
+
+#[test]
+fn flatten_errs_back() {
+    let mut results = reads().flatten_errs();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results.next_back(), Some(Err(NestedError::Outer(ReadError))));
+    assert_eq!(results.len(), 2);
+}
+
+#[test]
+fn box_errs() {
+    let results: Vec<Result<u32, Box<dyn Error>>> = reads().box_errs().collect();
+    assert_eq!(results[0].as_ref().ok(), Some(&1));
+    assert!(results[1].as_ref().unwrap_err().is::<ParseIntError>());
+    assert!(results[2].as_ref().unwrap_err().is::<ReadError>());
+}
+
+#[test]
+fn box_errs_deeply_nested() {
+    type Deep = Result<Result<Result<Result<u32, ParseIntError>, fmt::Error>, io::Error>, ReadError>;
+
+    let results: [Deep; 5] = [
+        Ok(Ok(Ok(Ok(1)))),
+        Ok(Ok(Ok("x".parse()))),
+        Ok(Ok(Err(fmt::Error))),
+        Ok(Err(io::Error::other("io"))),
+        Err(ReadError),
+    ];
+    let messages: Vec<_> =
+        results.into_iter().box_errs::<u32>().map(|result| result.map_err(|err| err.to_string())).collect();
+    assert_eq!(
+        messages,
+        [
+            Ok(1),
+            Err("invalid digit found in string".to_string()),
+            Err("an error occurred when formatting an argument".to_string()),
+            Err("io".to_string()),
+            Err("read failed".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn flatten_ok_iter() {
+    let batches = [Ok(vec![1, 2]), Err("first"), Ok(vec![]), Ok(vec![3]), Err("second")];
+    let items: Vec<_> = batches.into_iter().flatten_ok_iter().collect();
+    assert_eq!(items, [Ok(1), Ok(2), Err("first"), Ok(3), Err("second")]);
+}
+
+#[test]
+fn flatten_ok_iter_size_hint() {
+    let mut items = [Ok::<_, ()>([1, 2])].into_iter().flatten_ok_iter();
+    assert_eq!(items.size_hint(), (0, None));
+    assert_eq!(items.next(), Some(Ok(1)));
+    assert_eq!(items.size_hint(), (1, Some(1)));
+    assert_eq!(items.next(), Some(Ok(2)));
+    assert_eq!(items.next(), None);
+    assert_eq!(items.size_hint(), (0, Some(0)));
+}
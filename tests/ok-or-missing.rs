@@ -0,0 +1,50 @@
+use std::error::Error;
+
+use fluent_result::into::{Missing, MissingAt, OkOrMissing};
+
+#[test]
+fn ok_or_missing() {
+    assert_eq!(Some(42).ok_or_missing("answer"), Ok(42));
+    assert_eq!(None::<u32>.ok_or_missing("answer"), Err(Missing("answer")));
+}
+
+#[test]
+fn ok_or_missing_at() {
+    assert_eq!(Some(42).ok_or_missing_at(3), Ok(42));
+    assert_eq!(None::<u32>.ok_or_missing_at(3), Err(MissingAt(3)));
+}
+
+#[test]
+fn ok_or_missing_key() {
+    assert_eq!(Some(42).ok_or_missing_key(7), Ok(42));
+    assert_eq!(None::<u32>.ok_or_missing_key(7), Err(Missing("7".to_string())));
+}
+
+#[test]
+fn ok_or_missing_key_lazy() {
+    struct Unformattable;
+
+    impl std::fmt::Display for Unformattable {
+        fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            panic!("should not be formatted")
+        }
+    }
+
+    assert_eq!(Some(42).ok_or_missing_key(Unformattable), Ok(42));
+}
+
+#[test]
+fn display() {
+    assert_eq!(Missing("answer").to_string(), "missing value for `answer`");
+    assert_eq!(Missing("a.b".to_string()).to_string(), "missing value for `a.b`");
+    assert_eq!(MissingAt(3).to_string(), "missing value at index 3");
+}
+
+#[test]
+fn into_error() {
+    let err: Box<dyn Error> = None::<u32>.ok_or_missing("answer").unwrap_err().into();
+    assert_eq!(err.to_string(), "missing value for `answer`");
+
+    let err: Box<dyn Error> = None::<u32>.ok_or_missing_at(0).unwrap_err().into();
+    assert_eq!(err.to_string(), "missing value at index 0");
+}
@@ -23,3 +23,129 @@ fn into_err() {
     let err = "error".into_err::<()>();
     assert_eq!(err, Err("error"));
 }
+
+#[test]
+fn into_some_if() {
+    assert_eq!(42.into_some_if(|n| *n > 0), Some(42));
+    assert_eq!(0.into_some_if(|n| *n > 0), None);
+}
+
+#[test]
+fn into_some_unless() {
+    assert_eq!(42.into_some_unless(|n| *n > 0), None);
+    assert_eq!(0.into_some_unless(|n| *n > 0), Some(0));
+}
+
+#[test]
+fn into_ok_if() {
+    assert_eq!(42.into_ok_if(|n| *n > 0, "zero"), Ok(42));
+    assert_eq!(0.into_ok_if(|n| *n > 0, "zero"), Err("zero"));
+}
+
+#[test]
+fn into_ok_if_else() {
+    let vec = vec![1, 2, 3];
+    assert_eq!(vec.into_ok_if_else(|vec| vec.is_empty(), |vec| vec.len()), Err(3));
+    assert_eq!(Vec::<u32>::new().into_ok_if_else(|vec| vec.is_empty(), |_| panic!("should not be called")), Ok(vec![]));
+}
+
+#[test]
+fn into_err_if() {
+    assert_eq!(42.into_err_if(|n| *n > 0), Err(42));
+    assert_eq!(0.into_err_if(|n| *n > 0), Ok(0));
+}
+
+mod control_flow {
+    use std::ops::ControlFlow;
+
+    use fluent_result::into::{ControlFlowIntoResult, IntoControlFlow, ResultIntoControlFlow};
+
+    #[test]
+    fn into_break() {
+        assert_eq!(42.into_break::<()>(), ControlFlow::Break(42));
+    }
+
+    #[test]
+    fn into_continue() {
+        assert_eq!(42.into_continue::<()>(), ControlFlow::Continue(42));
+    }
+
+    #[test]
+    fn result_into_control_flow() {
+        assert_eq!(Ok::<u32, &str>(42).into_control_flow(), ControlFlow::Continue(42));
+        assert_eq!(Err::<u32, &str>("error").into_control_flow(), ControlFlow::Break("error"));
+    }
+
+    #[test]
+    fn control_flow_into_result() {
+        assert_eq!(ControlFlow::<&str, u32>::Continue(42).into_result(), Ok(42));
+        assert_eq!(ControlFlow::<&str, u32>::Break("error").into_result(), Err("error"));
+    }
+}
+
+mod poll {
+    use std::task::Poll;
+
+    use fluent_result::into::IntoPoll;
+
+    #[test]
+    fn into_ready() {
+        assert_eq!(42.into_ready(), Poll::Ready(42));
+    }
+
+    #[test]
+    fn into_ready_ok() {
+        assert_eq!(42.into_ready_ok::<()>(), Poll::Ready(Ok(42)));
+    }
+
+    #[test]
+    fn into_ready_err() {
+        assert_eq!(42.into_ready_err::<()>(), Poll::Ready(Err(42)));
+    }
+}
+
+mod pointer {
+    use std::cell::{Cell, RefCell};
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use fluent_result::into::{IntoCell, IntoPointer};
+
+    #[test]
+    fn into_cell() {
+        assert_eq!(42.into_cell(), Cell::new(42));
+        assert_eq!(42.into_ref_cell(), RefCell::new(42));
+    }
+
+    #[test]
+    fn into_pointer() {
+        assert_eq!(42.into_box(), Box::new(42));
+        assert_eq!(42.into_pin_box(), Box::pin(42));
+        assert_eq!(42.into_rc(), Rc::new(42));
+        assert_eq!(42.into_arc(), Arc::new(42));
+        assert_eq!(42.into_rc_ref_cell(), Rc::new(RefCell::new(42)));
+
+        let _: Pin<Box<u32>> = 42.into_pin_box();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn into_lock() {
+        use std::sync::{Mutex, RwLock};
+
+        use fluent_result::into::IntoLock;
+
+        let mutex: Mutex<u32> = 42.into_mutex();
+        assert_eq!(mutex.into_inner().unwrap(), 42);
+
+        let lock: RwLock<u32> = 42.into_rw_lock();
+        assert_eq!(lock.into_inner().unwrap(), 42);
+
+        let shared: Arc<Mutex<u32>> = 42.into_arc_mutex();
+        assert_eq!(*shared.lock().unwrap(), 42);
+
+        let shared: Arc<RwLock<u32>> = 42.into_arc_rw_lock();
+        assert_eq!(*shared.read().unwrap(), 42);
+    }
+}
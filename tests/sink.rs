@@ -43,3 +43,319 @@ fn sink_err() {
     assert!(log.is_empty());
     assert_eq!(option, Some(42));
 }
+
+#[test]
+fn sink_closure_inference() {
+    let mut messages = Vec::new();
+    "x".parse::<u32>().sink_err(|err| messages.push(err.to_string()));
+    Ok::<_, ()>("ok").sink_ok(|value| messages.push(value.to_uppercase()));
+    Some(2_u32).sink(|value| messages.push(value.pow(2).to_string()));
+    assert_eq!(messages, ["invalid digit found in string", "OK", "4"]);
+}
+
+mod targets {
+    use fluent_result::sink::{Sink, SinkOption, SinkResult, WriteSink};
+    #[cfg(feature = "std")]
+    use std::sync::mpsc;
+
+    #[test]
+    fn vec() {
+        let mut errors = Vec::new();
+        Err::<u32, _>("first").sink_err_into(&mut errors);
+        Ok::<_, &str>(42).sink_err_into(&mut errors);
+        Some("second").sink_into(&mut errors);
+        assert_eq!(errors, ["first", "second"]);
+    }
+
+    #[test]
+    fn option_keeps_last() {
+        let mut last = None;
+        Some(1).sink_into(&mut last);
+        None.sink_into(&mut last);
+        assert_eq!(last, Some(1));
+
+        Some(2).sink_into(&mut last);
+        assert_eq!(last, Some(2));
+    }
+
+    #[test]
+    fn counter() {
+        let mut count = 0_usize;
+        for result in [Ok(1), Err("first"), Ok(2), Err("second")] {
+            result.sink_ok_into(&mut count);
+        }
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn write() {
+        let mut log = String::new();
+        let mut sink = WriteSink(&mut log);
+        Some("first").sink_into(&mut sink);
+        Some(2).sink_into(&mut sink);
+        Err::<(), _>("third").sink_err_into(sink);
+        assert_eq!(log, "first\n2\nthird\n");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn sender() {
+        let (sender, receiver) = mpsc::channel();
+        Err::<u32, _>("fail").sink_err_into(&sender);
+        Ok::<_, &str>(42).sink_err_into(&sender);
+        drop(sender);
+        assert_eq!(receiver.iter().collect::<Vec<_>>(), ["fail"]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn sender_disconnected() {
+        let (sender, receiver) = mpsc::channel();
+        drop(receiver);
+        Some("dropped").sink_into(&sender);
+    }
+
+    #[test]
+    fn custom() {
+        struct Max<'a>(&'a mut u32);
+
+        impl Sink<u32> for Max<'_> {
+            fn accept(self, value: u32) {
+                *self.0 = (*self.0).max(value);
+            }
+        }
+
+        let mut max = 0;
+        for value in [3, 7, 5] {
+            Some(value).sink_into(Max(&mut max));
+        }
+        assert_eq!(max, 7);
+    }
+}
+
+mod combinators {
+    use fluent_result::sink::{Sink, SinkOption, SinkResult};
+
+    #[test]
+    fn map() {
+        let mut log = Vec::new();
+        let mut sink = (&mut log).map(|code: u16| format!("error {code}"));
+        Err::<(), _>(404).sink_err_into(&mut sink);
+        Err::<(), _>(500).sink_err_into(&mut sink);
+        Ok::<_, u16>(()).sink_err_into(sink);
+        assert_eq!(log, ["error 404", "error 500"]);
+    }
+
+    #[test]
+    fn filter() {
+        let mut log = Vec::new();
+        let mut sink = (&mut log).filter(|code: &u16| *code >= 500);
+        for code in [404, 500, 503] {
+            Some(code).sink_into(&mut sink);
+        }
+        Some(502).sink_into(sink);
+        assert_eq!(log, [500, 503, 502]);
+    }
+
+    #[test]
+    fn tee() {
+        let (mut log, mut count) = (Vec::new(), 0);
+        let mut sink = (&mut log).tee(&mut count);
+        Some("first").sink_into(&mut sink);
+        Some("second").sink_into(&mut sink);
+        Some("third").sink_into(sink);
+        assert_eq!(log, ["first", "second", "third"]);
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn first_only() {
+        let mut log = Vec::new();
+        let mut sink = (&mut log).first_only();
+        for result in [Ok(1), Err("first"), Err("second")] {
+            result.sink_ok(|_| {}).sink_into(&mut sink);
+        }
+        assert_eq!(sink.remaining(), 0);
+        assert_eq!(log, ["first"]);
+    }
+
+    #[test]
+    fn take() {
+        let mut log = Vec::new();
+        let mut sink = (&mut log).take(2);
+        for code in 0..5 {
+            Some(code).sink_into(&mut sink);
+        }
+        assert_eq!(log, [0, 1]);
+
+        let mut last = None;
+        Some(1).sink_into(Sink::take(&mut last, 0));
+        assert_eq!(last, None);
+    }
+
+    #[test]
+    fn dedup_by_key() {
+        let mut log = String::new();
+        let mut sink = (|err: &str| log.push_str(err)).dedup_by_key(|err| err.len());
+        for err in ["a", "b", "cd", "ef", "g"] {
+            Some(err).sink_into(&mut sink);
+        }
+        drop(sink);
+        assert_eq!(log, "acdg");
+    }
+
+    #[test]
+    fn counted() {
+        let mut sink = (|_: u32| {}).counted();
+        assert_eq!(sink.count(), 0);
+        for code in 0..3 {
+            Some(code).sink_into(&mut sink);
+        }
+        assert_eq!(sink.count(), 3);
+    }
+
+    #[test]
+    fn composed() {
+        let (mut log, mut metrics) = (Vec::new(), 0);
+        let mut sink = (&mut log).take(2).tee(&mut metrics).filter(|code: &u16| *code != 404).counted();
+        for code in [404, 500, 404, 502, 503] {
+            Err::<(), _>(code).sink_err_into(&mut sink);
+        }
+        assert_eq!(sink.count(), 5);
+        let _ = sink.into_inner();
+        assert_eq!(log, [500, 502]);
+        assert_eq!(metrics, 3);
+    }
+}
+
+mod iter {
+    use fluent_result::sink::{Sink, SinkOptionIter, SinkResultIter};
+
+    #[test]
+    fn sink_errs() {
+        let mut errors = Vec::new();
+        let results = [Ok(1), Err("first"), Ok(2), Err("second")];
+        let mut values = results.into_iter().sink_errs(|err| errors.push(err));
+        assert_eq!(values.next(), Some(1));
+        assert_eq!(values.next(), Some(2));
+        assert_eq!(values.next(), None);
+        drop(values);
+        assert_eq!(errors, ["first", "second"]);
+    }
+
+    #[test]
+    fn sink_errs_is_lazy() {
+        let mut count = 0;
+        let results = [Err("first"), Ok(1), Err("second")];
+        let mut values = results.into_iter().sink_errs_into(&mut count);
+        assert_eq!(values.next(), Some(1));
+        drop(values);
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn sink_errs_back() {
+        let mut errors = Vec::new();
+        let results = [Ok(1), Err("first"), Ok(2), Err("second")];
+        let values: Vec<_> = results.into_iter().sink_errs(|err| errors.push(err)).rev().collect();
+        assert_eq!(values, [2, 1]);
+        assert_eq!(errors, ["second", "first"]);
+    }
+
+    #[test]
+    fn sink_errs_with_combinator() {
+        let mut errors = Vec::new();
+        let results = [Err(1), Err(2), Ok(()), Err(3)];
+        let values = results.into_iter().sink_errs_into((&mut errors).take(2)).count();
+        assert_eq!(values, 1);
+        assert_eq!(errors, [1, 2]);
+    }
+
+    #[test]
+    fn sink_oks() {
+        let mut sum = 0;
+        let results = [Ok(1), Err("first"), Ok(2)];
+        let errors: Vec<_> = results.into_iter().sink_oks(|value| sum += value).collect();
+        assert_eq!(errors, ["first"]);
+        assert_eq!(sum, 3);
+    }
+
+    #[test]
+    fn sink_somes() {
+        let mut errors = Vec::new();
+        let checks = [None, Some("first"), None, Some("second")];
+        let passed: Vec<_> = checks.into_iter().sink_somes(|err| errors.push(err)).collect();
+        assert_eq!(passed, [0, 2]);
+        assert_eq!(errors, ["first", "second"]);
+    }
+
+    #[test]
+    fn sink_somes_rev() {
+        let mut errors = Vec::new();
+        let checks = [None, Some("first"), None, Some("second"), None];
+        let mut passed = checks.into_iter().sink_somes(|err| errors.push(err));
+        assert_eq!(passed.next_back(), Some(4));
+        assert_eq!(passed.next(), Some(0));
+        assert_eq!(passed.next_back(), Some(2));
+        assert_eq!(passed.next(), None);
+        assert_eq!(errors, ["second", "first"]);
+    }
+
+    #[test]
+    fn sink_oks_into() {
+        let mut values = Vec::new();
+        let results = [Ok(1), Err("first"), Ok(2)];
+        let errors: Vec<_> = results.into_iter().sink_oks_into(&mut values).collect();
+        assert_eq!(errors, ["first"]);
+        assert_eq!(values, [1, 2]);
+    }
+
+    #[test]
+    fn sink_somes_into() {
+        let mut last = None;
+        let checks = [None, Some("first"), None, Some("second")];
+        let passed = checks.into_iter().sink_somes_into(&mut last).count();
+        assert_eq!(passed, 2);
+        assert_eq!(last, Some("second"));
+    }
+
+    #[test]
+    fn closure_inference() {
+        let mut messages = Vec::new();
+        let results = ["1", "x"].iter().map(|s| s.parse::<u32>());
+        let values: Vec<_> = results.sink_errs(|err| messages.push(err.to_string())).collect();
+        assert_eq!(values, [1]);
+
+        let results = [Ok::<_, &str>("ok"), Err("fail")];
+        let errors: Vec<_> = results.into_iter().sink_oks(|value| messages.push(value.to_uppercase())).collect();
+        assert_eq!(errors, ["fail"]);
+
+        let passed =
+            [Some(3_u32), None].into_iter().sink_somes(|value| messages.push(value.pow(2).to_string())).count();
+        assert_eq!(passed, 1);
+        assert_eq!(messages, ["invalid digit found in string", "OK", "9"]);
+    }
+
+    #[test]
+    fn drain() {
+        let (mut values, mut errors) = (Vec::new(), Vec::new());
+        let results = [Ok(1_u32), Err("first"), Ok(2), Err("second")];
+        let counts = results.into_iter().drain(|value| values.push(value.pow(2)), |err| errors.push(err.len()));
+        assert_eq!(counts, (2, 2));
+        assert_eq!(values, [1, 4]);
+        assert_eq!(errors, [5, 6]);
+
+        let counts = core::iter::empty::<Result<(), ()>>().drain(|()| {}, |()| {});
+        assert_eq!(counts, (0, 0));
+    }
+
+    #[test]
+    fn drain_into() {
+        let (mut values, mut errors) = (Vec::new(), 0);
+        let results = [Ok(1), Err("first"), Ok(2), Err("second"), Err("third")];
+        let counts = results.into_iter().drain_into(&mut values, &mut errors);
+        assert_eq!(counts, (2, 3));
+        assert_eq!(values, [1, 2]);
+        assert_eq!(errors, 3);
+    }
+}
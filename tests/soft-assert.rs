@@ -0,0 +1,59 @@
+use fluent_result::expect::{FailureKind, SoftAssert};
+
+#[test]
+fn soft_assert_passes() {
+    let mut soft = SoftAssert::new();
+    soft.assert_true(true)
+        .expect_true(true, "true")
+        .assert_false(false)
+        .expect_false(false, "false")
+        .assert_none(None::<u8>)
+        .expect_none(None::<u8>, "none");
+
+    assert!(soft.failures().is_empty());
+    assert_eq!(soft.finish(), Ok(()));
+}
+
+#[test]
+fn soft_assert_collects_failures() {
+    let mut soft = SoftAssert::new();
+    soft.assert_true(false);
+    let line = line!() - 1;
+    soft.expect_false(true, "should be false");
+    soft.assert_none(Some(1));
+
+    let failures = soft.finish().expect_err("should fail");
+    let failures: Vec<_> = failures.iter().map(|f| (f.kind(), f.message(), f.location().line())).collect();
+    assert_eq!(
+        failures,
+        [
+            (FailureKind::ExpectedTrue, "assertion failed: expected `true` but was `false`", line),
+            (FailureKind::ExpectedFalse, "should be false", line + 2),
+            (FailureKind::ExpectedNone, "called `Option::assert_none()` on a `Some` value", line + 3),
+        ]
+    );
+}
+
+#[test]
+fn soft_assert_report() {
+    let mut soft = SoftAssert::new();
+    soft.expect_true(false, "first").expect_none(Some(()), "second");
+    let line = line!() - 1;
+
+    let report = soft.finish().expect_err("should fail").to_string();
+    let file = file!();
+    assert_eq!(report, format!("2 errors:\n    0: {file}:{line}:10: first\n    1: {file}:{line}:38: second"));
+}
+
+#[test]
+#[should_panic(expected = "1 error:")]
+fn soft_assert_panics_on_drop() {
+    let mut soft = SoftAssert::new();
+    soft.assert_true(false);
+}
+
+#[test]
+fn soft_assert_drop_without_failures() {
+    let mut soft = SoftAssert::new();
+    soft.assert_true(true);
+}
@@ -0,0 +1,192 @@
+#![cfg(feature = "testing")]
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use fluent_result::nested::{BoxErr, FlattenErr, NestedError};
+use fluent_result::testing::{
+    assert_err_is, assert_err_matches, assert_nested_inner, assert_nested_outer, assert_ok_eq, assert_some_eq,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Root;
+
+impl Display for Root {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "root cause")
+    }
+}
+
+impl Error for Root {}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Wrapper(Root);
+
+impl Display for Wrapper {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wrapper")
+    }
+}
+
+impl Error for Wrapper {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn assert_ok_eq_ok() {
+    let result: Result<u32, Root> = Ok(42);
+    assert_ok_eq!(result, 42);
+}
+
+#[test]
+#[should_panic(expected = "result was `Ok`, but the value did not match")]
+fn assert_ok_eq_mismatch() {
+    let result: Result<u32, Root> = Ok(42);
+    assert_ok_eq!(result, 7);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: expected `Ok`, but was `Err`: Wrapper(Root)\n\nCaused by:\n    0: root cause"
+)]
+fn assert_ok_eq_err_chain() {
+    let result: Result<u32, Wrapper> = Err(Wrapper(Root));
+    assert_ok_eq!(result, 42);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: expected `Ok`, but was `Err`: \"oops\"")]
+fn assert_ok_eq_err_debug() {
+    let result: Result<u32, &str> = Err("oops");
+    assert_ok_eq!(result, 42);
+}
+
+#[test]
+fn assert_err_matches_err() {
+    let result: Result<u32, Wrapper> = Err(Wrapper(Root));
+    assert_err_matches!(result, Wrapper(Root));
+    assert_err_matches!(result, e if e.0 == Root);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `Err` did not match `Some(1)`: Some(2)")]
+fn assert_err_matches_mismatch() {
+    let result: Result<u32, Option<u32>> = Err(Some(2));
+    assert_err_matches!(result, Some(1));
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: expected `Err`, but was `Ok`: 42")]
+fn assert_err_matches_ok() {
+    let result: Result<u32, Root> = Ok(42);
+    assert_err_matches!(result, Root);
+}
+
+#[test]
+fn assert_some_eq_some() {
+    assert_some_eq!(Some(42), 42);
+}
+
+#[test]
+#[should_panic(expected = "option was `Some`, but the value did not match")]
+fn assert_some_eq_mismatch() {
+    assert_some_eq!(Some(42), 7);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: expected `Some`, but was `None`")]
+fn assert_some_eq_none() {
+    assert_some_eq!(None::<u32>, 42);
+}
+
+#[test]
+fn assert_err_is_match() {
+    let result: Result<Result<u32, Root>, std::fmt::Error> = Ok(Err(Root));
+    let boxed: Result<u32, Box<dyn Error>> = result.box_err();
+    assert_eq!(assert_err_is::<Root>(&boxed), &Root);
+}
+
+#[test]
+fn assert_err_is_send_sync() {
+    let boxed: Result<u32, Box<dyn Error + Send + Sync>> = Err(Box::new(Root));
+    assert_eq!(assert_err_is::<Root>(&boxed), &Root);
+
+    let boxed: Result<u32, Box<dyn Error + Send>> = Err(Box::new(Root));
+    assert_eq!(assert_err_is::<Root>(&boxed), &Root);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: expected an error of type `core::fmt::Error`: Wrapper(Root)\n\nCaused by:\n    0: root cause"
+)]
+fn assert_err_is_mismatch() {
+    let result: Result<Result<u32, Wrapper>, std::fmt::Error> = Ok(Err(Wrapper(Root)));
+    let boxed: Result<u32, Box<dyn Error>> = result.box_err();
+    assert_err_is::<std::fmt::Error>(&boxed);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: expected `Ok`, but was `Err`: Wrapper(Root)\n\nCaused by:\n    0: root cause"
+)]
+fn assert_ok_eq_boxed_chain() {
+    let result: Result<Result<u32, Wrapper>, std::fmt::Error> = Ok(Err(Wrapper(Root)));
+    let boxed: Result<u32, Box<dyn Error>> = result.box_err();
+    assert_ok_eq!(boxed, 42);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: expected `Ok`, but was `Err`: Wrapper(Root)\n\nCaused by:\n    0: root cause"
+)]
+fn assert_ok_eq_boxed_send_chain() {
+    let boxed: Result<u32, Box<dyn Error + Send>> = Err(Box::new(Wrapper(Root)));
+    assert_ok_eq!(boxed, 42);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: expected `Ok`, but was `Err`: Wrapper(Root)\n\nCaused by:\n    0: root cause"
+)]
+fn assert_ok_eq_boxed_send_sync_chain() {
+    let boxed: Result<u32, Box<dyn Error + Send + Sync>> = Err(Box::new(Wrapper(Root)));
+    assert_ok_eq!(boxed, 42);
+}
+
+#[test]
+fn assert_nested_inner_inner() {
+    let result: Result<Result<u32, Root>, std::fmt::Error> = Ok(Err(Root));
+    assert_eq!(assert_nested_inner!(result.flatten_err()), Root);
+
+    let result: Result<Result<u32, Root>, std::fmt::Error> = Ok(Err(Root));
+    assert_eq!(assert_nested_inner!(result.flatten_err(), Root), Root);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: expected `NestedError::Inner`, but was `NestedError::Outer`: Error")]
+fn assert_nested_inner_outer() {
+    let result: Result<Result<u32, Root>, std::fmt::Error> = Err(std::fmt::Error);
+    assert_nested_inner!(result.flatten_err());
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `NestedError::Inner` did not match `Some(1)`: Some(2)")]
+fn assert_nested_inner_mismatch() {
+    let result: Result<Result<u32, Option<u32>>, Root> = Ok(Err(Some(2)));
+    assert_nested_inner!(result.flatten_err(), Some(1));
+}
+
+#[test]
+fn assert_nested_outer_outer() {
+    let error: NestedError<Root, u8> = NestedError::Outer(2);
+    assert_eq!(assert_nested_outer!(Err::<u32, _>(error), 2), 2);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: expected `NestedError::Outer`, but was `Ok`: 42")]
+fn assert_nested_outer_ok() {
+    let result: Result<Result<u32, Root>, Root> = Ok(Ok(42));
+    assert_nested_outer!(result.flatten_err());
+}
@@ -0,0 +1,68 @@
+use std::error::Error;
+use std::num::ParseIntError;
+
+use fluent_result::expect::ErrorReport;
+use fluent_result::into::{AtIndex, TryCollectIndexed};
+use fluent_result::nested::{BoxErr, FlattenErr, NestedError};
+
+fn parse(inputs: &[&str]) -> impl ExactSizeIterator<Item = Result<u32, ParseIntError>> {
+    inputs.iter().map(|input| input.parse())
+}
+
+#[test]
+fn try_collect_indexed_ok() {
+    assert_eq!(parse(&["1", "2"]).try_collect_indexed::<Vec<_>>(), Ok(vec![1, 2]));
+    assert_eq!(parse(&[]).try_collect_indexed::<Vec<_>>(), Ok(vec![]));
+}
+
+#[test]
+fn try_collect_indexed_err() {
+    let err = parse(&["1", "2", "x", ""]).try_collect_indexed::<Vec<_>>().unwrap_err();
+    assert_eq!(err.index, 2);
+    assert_eq!(err.error.to_string(), "invalid digit found in string");
+}
+
+#[test]
+fn try_collect_indexed_stops_at_first_err() {
+    let mut seen = 0;
+    let results = [Ok(1), Err("first"), Err("second")].into_iter().inspect(|_| seen += 1);
+    assert_eq!(results.try_collect_indexed::<Vec<_>>(), Err(AtIndex { index: 1, error: "first" }));
+    assert_eq!(seen, 2);
+}
+
+#[test]
+fn enumerate_errs() {
+    let mut results = [Err("first"), Ok(1), Err("second")].into_iter().enumerate_errs();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results.next(), Some(Err(AtIndex { index: 0, error: "first" })));
+    assert_eq!(results.next(), Some(Ok(1)));
+    assert_eq!(results.next(), Some(Err(AtIndex { index: 2, error: "second" })));
+    assert_eq!(results.next(), None);
+}
+
+#[test]
+fn at_index_error() {
+    let err = parse(&["x"]).try_collect_indexed::<Vec<_>>().unwrap_err();
+    assert_eq!(err.to_string(), "error at index 0");
+    assert_eq!(err.source().map(ToString::to_string), Some("invalid digit found in string".to_string()));
+    assert_eq!(
+        ErrorReport::new(&err).to_string(),
+        "error at index 0\n\nCaused by:\n    0: invalid digit found in string"
+    );
+}
+
+#[test]
+fn at_index_box_err() {
+    let boxed = parse(&["1", "x"]).try_collect_indexed::<Vec<_>>().box_err();
+    let err = boxed.unwrap_err();
+    assert_eq!(err.downcast_ref::<AtIndex<ParseIntError>>().map(|err| err.index), Some(1));
+}
+
+#[test]
+fn at_index_flatten_err() {
+    let read: Result<Result<Vec<u32>, AtIndex<ParseIntError>>, std::fmt::Error> =
+        Ok(parse(&["1", "x"]).try_collect_indexed());
+    let err = read.flatten_err().unwrap_err();
+    assert!(matches!(err, NestedError::Inner(AtIndex { index: 1, .. })));
+    assert_eq!(err.source().map(ToString::to_string), Some("error at index 1".to_string()));
+}
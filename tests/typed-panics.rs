@@ -0,0 +1,38 @@
+#![cfg(feature = "std")]
+
+use std::panic::catch_unwind;
+
+use fluent_result::bool::rls::Expect;
+use fluent_result::expect::rls::ExpectNone;
+use fluent_result::expect::{AssertionFailure, FailureKind, set_typed_panics};
+
+#[test]
+fn typed_panic_bool() {
+    set_typed_panics(true);
+
+    let payload = catch_unwind(|| false.assert_true()).unwrap_err();
+    let line = line!() - 1;
+
+    let failure = AssertionFailure::from_payload(payload.as_ref()).expect("should be a failure");
+    assert_eq!(failure.kind(), FailureKind::ExpectedTrue);
+    assert_eq!(failure.message(), "assertion failed: expected `true` but was `false`");
+    assert_eq!(failure.location().file(), file!());
+    assert_eq!(failure.location().line(), line);
+}
+
+#[test]
+fn typed_panic_none() {
+    set_typed_panics(true);
+
+    let payload = catch_unwind(|| Some(1).expect_none("should be none")).unwrap_err();
+
+    let failure = AssertionFailure::from_payload(payload.as_ref()).expect("should be a failure");
+    assert_eq!(failure.kind(), FailureKind::ExpectedNone);
+    assert_eq!(failure.message(), "should be none");
+}
+
+#[test]
+fn from_payload_other_panic() {
+    let payload = catch_unwind(|| panic!("other")).unwrap_err();
+    assert_eq!(AssertionFailure::from_payload(payload.as_ref()), None);
+}